@@ -1,6 +1,7 @@
 use std::fmt::{self, Display, Formatter};
 use std::path::PathBuf;
 
+use chrono::{DateTime, TimeZone, Utc};
 use semver::Version;
 
 use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
@@ -129,6 +130,28 @@ pub struct SharedArgs {
         value_parser = clap::value_parser!(DiagnosticFormat)
     )]
     pub diagnostic_format: DiagnosticFormat,
+
+    /// The document's creation date formatted as a UNIX timestamp, used in
+    /// place of the current date to make the output reproducible
+    ///
+    /// For more information, see <https://reproducible-builds.org/specs/source-date-epoch/>.
+    #[clap(
+        long = "creation-timestamp",
+        env = "SOURCE_DATE_EPOCH",
+        value_name = "UNIX_TIMESTAMP",
+        value_parser = parse_source_date_epoch,
+    )]
+    pub creation_timestamp: Option<DateTime<Utc>>,
+}
+
+/// Parses a UNIX timestamp according to <https://reproducible-builds.org/specs/source-date-epoch/>.
+fn parse_source_date_epoch(raw: &str) -> Result<DateTime<Utc>, String> {
+    let timestamp: i64 = raw
+        .parse()
+        .map_err(|err| format!("timestamp must be decimal integer ({err})"))?;
+    Utc.timestamp_opt(timestamp, 0)
+        .single()
+        .ok_or_else(|| "timestamp out of range".to_string())
 }
 
 /// Lists all discovered fonts in system and custom font paths
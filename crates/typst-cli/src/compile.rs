@@ -180,8 +180,13 @@ fn export_image(
         };
         match fmt {
             ImageExportFormat::Png => {
-                let pixmap =
-                    typst::export::render(frame, command.ppi / 72.0, Color::WHITE);
+                // Pages are filled during layout, so the canvas itself stays
+                // transparent to support pages without a fill.
+                let pixmap = typst::export::render(
+                    frame,
+                    command.ppi / 72.0,
+                    Color::from_u8(0, 0, 0, 0),
+                );
                 pixmap
                     .save_png(path)
                     .map_err(|err| eco_format!("failed to write PNG file ({err})"))?;
@@ -4,7 +4,7 @@ use std::fs;
 use std::hash::Hash;
 use std::path::{Path, PathBuf};
 
-use chrono::{DateTime, Datelike, Local};
+use chrono::{DateTime, Datelike, Local, Utc};
 use comemo::Prehashed;
 use filetime::FileTime;
 use same_file::Handle;
@@ -42,6 +42,9 @@ pub struct SystemWorld {
     /// The current datetime if requested. This is stored here to ensure it is
     /// always the same within one compilation. Reset between compilations.
     now: OnceCell<DateTime<Local>>,
+    /// A fixed creation timestamp that replaces the current datetime, for
+    /// reproducible output.
+    creation_timestamp: Option<DateTime<Utc>>,
 }
 
 impl SystemWorld {
@@ -81,6 +84,7 @@ impl SystemWorld {
             hashes: RefCell::default(),
             slots: RefCell::default(),
             now: OnceCell::new(),
+            creation_timestamp: command.creation_timestamp,
         })
     }
 
@@ -150,11 +154,19 @@ impl World for SystemWorld {
     }
 
     fn today(&self, offset: Option<i64>) -> Option<Datetime> {
-        let now = self.now.get_or_init(chrono::Local::now);
-
-        let naive = match offset {
-            None => now.naive_local(),
-            Some(o) => now.naive_utc() + chrono::Duration::hours(o),
+        let naive = match self.creation_timestamp {
+            // A fixed timestamp is always interpreted as UTC unless an
+            // explicit offset is requested.
+            Some(timestamp) => {
+                timestamp.naive_utc() + chrono::Duration::hours(offset.unwrap_or(0))
+            }
+            None => {
+                let now = self.now.get_or_init(chrono::Local::now);
+                match offset {
+                    None => now.naive_local(),
+                    Some(o) => now.naive_utc() + chrono::Duration::hours(o),
+                }
+            }
         };
 
         Datetime::from_ymd(
@@ -748,6 +748,7 @@ const TYPE_ORDER: &[&str] = &[
     "alignment",
     "alignment2d",
     "stroke",
+    "coord",
 ];
 
 /// Data about a collection of functions.
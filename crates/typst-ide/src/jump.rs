@@ -38,7 +38,7 @@ pub fn jump_from_click(
 
     // Try to find a link first.
     for (pos, item) in frame.items() {
-        if let FrameItem::Meta(Meta::Link(dest), size) = item {
+        if let FrameItem::Meta(Meta::Link(dest, _), size) = item {
             if is_in_rect(*pos, *size, click) {
                 return Some(match dest {
                     Destination::Url(url) => Jump::Url(url.clone()),
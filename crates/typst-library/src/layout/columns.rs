@@ -4,10 +4,10 @@ use crate::text::TextElem;
 /// Separates a region into multiple equally sized columns.
 ///
 /// The `column` function allows to separate the interior of any container into
-/// multiple columns. It will not equalize the height of the columns, instead,
-/// the columns will take up the height of their container or the remaining
-/// height on the page. The columns function can break across pages if
-/// necessary.
+/// multiple columns. By default, it will not equalize the height of the
+/// columns, instead, the columns will take up the height of their container or
+/// the remaining height on the page. The columns function can break across
+/// pages if necessary.
 ///
 /// If you need to insert columns across your whole document, you can use the
 /// [`{page}` function's `columns` parameter]($page.columns) instead.
@@ -44,6 +44,25 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to balance the columns in the final region.
+    ///
+    /// When enabled, the columns on the last page (or in the last region of
+    /// a container) are made as equally tall as possible instead of filling
+    /// the first column completely and leaving the others short. This also
+    /// applies to [page columns]($page.columns).
+    ///
+    /// ```example
+    /// #set page(height: 120pt)
+    /// #columns(2, balance: true)[
+    ///   This text is split evenly
+    ///   into both columns instead
+    ///   of filling up the first
+    ///   column before moving on.
+    /// ]
+    /// ```
+    #[default(false)]
+    pub balance: bool,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
@@ -70,28 +89,38 @@ impl Layout for ColumnsElem {
         let gutter = self.gutter(styles).relative_to(regions.base().x);
         let width = (regions.size.x - gutter * (columns - 1) as f64) / columns as f64;
 
-        let backlog: Vec<_> = std::iter::once(&regions.size.y)
-            .chain(regions.backlog)
-            .flat_map(|&height| std::iter::repeat(height).take(columns))
-            .skip(1)
-            .collect();
-
-        // Create the pod regions.
-        let pod = Regions {
-            size: Size::new(width, regions.size.y),
-            full: regions.full,
-            backlog: &backlog,
-            last: regions.last,
-            expand: Axes::new(true, regions.expand.y),
-            root: regions.root,
-        };
-
         // Layout the children.
-        let mut frames = body.layout(vt, styles, pod)?.into_iter();
-        let mut finished = vec![];
+        let mut frames =
+            layout_columns(vt, &body, styles, regions, columns, width, None)?;
+        let total_regions = (frames.len() as f32 / columns as f32).ceil() as usize;
+
+        // Search for the smallest column height in the final region with
+        // which the content still fits into the same number of regions.
+        if self.balance(styles) && columns > 1 && total_regions > 0 {
+            let last = total_regions - 1;
+            let fits = |frames: &[Frame]| frames.len() <= total_regions * columns;
+            let mut lo = Abs::zero();
+            let mut hi = regions.iter().nth(last).map_or(Abs::zero(), |size| size.y);
+            if hi.is_finite() {
+                while hi - lo > Abs::pt(0.5) {
+                    let mid = (lo + hi) / 2.0;
+                    let limit = Some((last, mid));
+                    let balanced = layout_columns(
+                        vt, &body, styles, regions, columns, width, limit,
+                    )?;
+                    if fits(&balanced) {
+                        hi = mid;
+                        frames = balanced;
+                    } else {
+                        lo = mid;
+                    }
+                }
+            }
+        }
 
+        let mut frames = frames.into_iter();
+        let mut finished = vec![];
         let dir = TextElem::dir_in(styles);
-        let total_regions = (frames.len() as f32 / columns as f32).ceil() as usize;
 
         // Stitch together the columns for each region.
         for region in regions.iter().take(total_regions) {
@@ -127,6 +156,50 @@ impl Layout for ColumnsElem {
     }
 }
 
+/// Layout the body into columns of the given width.
+///
+/// With a `limit`, the columns in the region with the given index are at
+/// most as tall as the given height.
+fn layout_columns(
+    vt: &mut Vt,
+    body: &Content,
+    styles: StyleChain,
+    regions: Regions,
+    columns: usize,
+    width: Abs,
+    limit: Option<(usize, Abs)>,
+) -> SourceResult<Vec<Frame>> {
+    let mut heights: Vec<_> = std::iter::once(regions.size.y)
+        .chain(regions.backlog.iter().copied())
+        .collect();
+    if let Some((index, height)) = limit {
+        while heights.len() <= index {
+            let Some(last) = regions.last else { break };
+            heights.push(last);
+        }
+        if let Some(slot) = heights.get_mut(index) {
+            slot.set_min(height);
+        }
+    }
+
+    let heights: Vec<_> = heights
+        .into_iter()
+        .flat_map(|height| std::iter::repeat(height).take(columns))
+        .collect();
+
+    // Create the pod regions.
+    let pod = Regions {
+        size: Size::new(width, heights[0]),
+        full: regions.full,
+        backlog: &heights[1..],
+        last: regions.last,
+        expand: Axes::new(true, regions.expand.y),
+        root: regions.root,
+    };
+
+    Ok(body.layout(vt, styles, pod)?.into_frames())
+}
+
 /// Forces a column break.
 ///
 /// The function will behave like a [page break]($pagebreak) when used in a
@@ -153,7 +226,8 @@ impl Layout for ColumnsElem {
 #[elem(title = "Column Break", Behave)]
 pub struct ColbreakElem {
     /// If `{true}`, the column break is skipped if the current column is
-    /// already empty.
+    /// already empty. Multiple adjacent weak column breaks thus collapse into
+    /// one.
     #[default(false)]
     pub weak: bool,
 }
@@ -3,6 +3,7 @@ use typst::eval::AutoValue;
 use super::VElem;
 use crate::layout::Spacing;
 use crate::prelude::*;
+use crate::text::{first_font, TextElem};
 
 /// An inline-level container that sizes content.
 ///
@@ -24,6 +25,10 @@ use crate::prelude::*;
 pub struct BoxElem {
     /// The width of the box.
     ///
+    /// With `{auto}`, the box shrinks to fit its content. A relative width like
+    /// `{100%}` is resolved against the region the box is laid out in, so a
+    /// `{100%}`-width box fills the whole line.
+    ///
     /// Boxes can have [fractional]($fraction) widths, as the example below
     /// demonstrates.
     ///
@@ -34,6 +39,13 @@ pub struct BoxElem {
     /// ```example
     /// Line in #box(width: 1fr, line(length: 100%)) between.
     /// ```
+    #[parse({
+        let option: Option<Spanned<Sizing>> = args.named("width")?;
+        if let Some(Spanned { v: Sizing::Bounded { .. }, span }) = option {
+            bail!(span, "boxes cannot have a bounded width");
+        }
+        option.map(|spanned| spanned.v)
+    })]
     pub width: Sizing,
 
     /// The height of the box.
@@ -65,15 +77,20 @@ pub struct BoxElem {
 
     /// How much to pad the box's content.
     ///
+    /// If set to `{auto}`, the box is padded on all sides by the x-height of
+    /// the current font, so that the padding scales with the text size.
+    ///
     /// _Note:_ When the box contains text, its exact size depends on the
     /// current [text edges]($text.top-edge).
     ///
     /// ```example
     /// #rect(inset: 0pt)[Tight]
+    /// #box(inset: auto, stroke: 0.5pt)[Snug]
     /// ```
     #[resolve]
     #[fold]
-    pub inset: Sides<Option<Rel<Length>>>,
+    #[default(Smart::Custom(Sides::splat(Rel::zero())))]
+    pub inset: Smart<Sides<Option<Rel<Length>>>>,
 
     /// How much to expand the box's size without affecting the layout.
     ///
@@ -112,7 +129,7 @@ impl Layout for BoxElem {
         regions: Regions,
     ) -> SourceResult<Fragment> {
         let width = match self.width(styles) {
-            Sizing::Auto => Smart::Auto,
+            Sizing::Auto | Sizing::Bounded { .. } => Smart::Auto,
             Sizing::Rel(rel) => Smart::Custom(rel),
             Sizing::Fr(_) => Smart::Custom(Ratio::one().into()),
         };
@@ -127,7 +144,7 @@ impl Layout for BoxElem {
 
         // Apply inset.
         let mut body = self.body(styles).unwrap_or_default();
-        let inset = self.inset(styles);
+        let inset = self.inset(styles).unwrap_or_else(|| text_inset(vt, styles));
         if inset.iter().any(|v| !v.is_zero()) {
             body = body.padded(inset.map(|side| side.map(Length::from)));
         }
@@ -169,6 +186,14 @@ impl Layout for BoxElem {
     }
 }
 
+/// The padding of a container with `{auto}` inset: The x-height of the current
+/// font on all sides.
+pub(crate) fn text_inset(vt: &Vt, styles: StyleChain) -> Sides<Rel<Abs>> {
+    let x_height =
+        first_font(vt, styles).map_or(Em::new(0.5), |font| font.metrics().x_height);
+    Sides::splat(x_height.at(TextElem::size_in(styles)).into())
+}
+
 /// A block-level container.
 ///
 /// Such a container can be used to separate content, size it, and give it a
@@ -458,6 +483,9 @@ pub enum Sizing {
     /// A track size specified as a fraction of the remaining free space in the
     /// parent.
     Fr(Fr),
+    /// A track that fits its cell's contents, but is at least `min` and at most
+    /// `max` large.
+    Bounded { min: Rel<Length>, max: Option<Rel<Length>> },
 }
 
 impl Sizing {
@@ -465,6 +493,11 @@ impl Sizing {
     pub fn is_fractional(self) -> bool {
         matches!(self, Self::Fr(_))
     }
+
+    /// Whether this sizing fits the contents, with or without bounds.
+    pub fn is_auto(self) -> bool {
+        matches!(self, Self::Auto | Self::Bounded { .. })
+    }
 }
 
 impl Default for Sizing {
@@ -488,8 +521,18 @@ cast! {
         Self::Auto => Value::Auto,
         Self::Rel(rel) => rel.into_value(),
         Self::Fr(fr) => fr.into_value(),
+        Self::Bounded { min, max } => dict! {
+            "min" => min.into_value(),
+            "max" => max.into_value(),
+        }.into_value(),
     },
     _: AutoValue => Self::Auto,
     v: Rel<Length> => Self::Rel(v),
     v: Fr => Self::Fr(v),
+    mut dict: Dict => {
+        let min = dict.take("min").ok().map(Value::cast).transpose()?;
+        let max = dict.take("max").ok().map(Value::cast).transpose()?;
+        dict.finish(&["min", "max"])?;
+        Self::Bounded { min: min.unwrap_or_default(), max: max.flatten() }
+    },
 }
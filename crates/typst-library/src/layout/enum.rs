@@ -63,7 +63,7 @@ use super::GridLayouter;
 /// Enumeration items can contain multiple paragraphs and other block-level
 /// content. All content that is indented more than an item's marker becomes
 /// part of that item.
-#[elem(scope, title = "Numbered List", Layout)]
+#[elem(scope, title = "Numbered List", Locatable, Synthesize, Layout)]
 pub struct EnumElem {
     /// If this is `{false}`, the items are spaced apart with
     /// [enum spacing]($enum.spacing). If it is `{true}`, they use normal
@@ -111,15 +111,27 @@ pub struct EnumElem {
 
     /// Which number to start the enumeration with.
     ///
+    /// If set to `{auto}`, the enumeration continues the numbering of the
+    /// previous enumeration at the same nesting depth. This way, a list can be
+    /// interrupted by other content and resumed afterwards.
+    ///
     /// ```example
     /// #enum(
     ///   start: 3,
     ///   [Skipping],
     ///   [Ahead],
     /// )
+    ///
+    /// + Mix
+    /// + Stir
+    ///
+    /// Let it rest for an hour.
+    ///
+    /// #set enum(start: auto)
+    /// + Bake
     /// ```
-    #[default(1)]
-    pub start: usize,
+    #[default(Smart::Custom(1))]
+    pub start: Smart<usize>,
 
     /// Whether to display the full numbering, including the numbers of
     /// all parent enumerations.
@@ -192,6 +204,11 @@ pub struct EnumElem {
     #[internal]
     #[fold]
     parents: Parent,
+
+    /// The nesting depth of the enumeration.
+    #[internal]
+    #[synthesized]
+    depth: usize,
 }
 
 #[scope]
@@ -200,6 +217,43 @@ impl EnumElem {
     type EnumItem;
 }
 
+impl EnumElem {
+    /// The number that follows the last item of this enumeration.
+    fn next_number(&self) -> usize {
+        let mut number = self.start(StyleChain::default()).unwrap_or(1);
+        for item in self.children() {
+            number = item.number(StyleChain::default()).unwrap_or(number);
+            number = number.saturating_add(1);
+        }
+        number
+    }
+}
+
+impl Synthesize for EnumElem {
+    fn synthesize(&mut self, vt: &mut Vt, styles: StyleChain) -> SourceResult<()> {
+        let depth = self.parents(styles).len();
+        let start = match self.start(styles) {
+            Smart::Custom(start) => start,
+            Smart::Auto => {
+                let selector = Self::elem()
+                    .select()
+                    .before(self.0.location().unwrap().into(), false);
+                vt.introspector
+                    .query(&selector)
+                    .iter()
+                    .rev()
+                    .filter_map(|elem| elem.to::<Self>())
+                    .find(|elem| elem.depth() == depth)
+                    .map_or(1, Self::next_number)
+            }
+        };
+
+        self.push_start(Smart::Custom(start));
+        self.push_depth(depth);
+        Ok(())
+    }
+}
+
 impl Layout for EnumElem {
     #[tracing::instrument(name = "EnumElem::layout", skip_all)]
     fn layout(
@@ -219,7 +273,7 @@ impl Layout for EnumElem {
         };
 
         let mut cells = vec![];
-        let mut number = self.start(styles);
+        let mut number = self.start(styles).unwrap_or(1);
         let mut parents = self.parents(styles);
         let full = self.full(styles);
 
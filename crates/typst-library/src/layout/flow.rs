@@ -148,6 +148,21 @@ impl FlowItem {
             Self::Frame { frame, .. } | Self::Footnote(frame) => frame.height(),
         }
     }
+
+    /// Whether the item is a visible frame.
+    ///
+    /// Empty frames and frames that only carry metadata, like the ones
+    /// produced by counter and state updates, don't count: Spacing after them
+    /// is still at the start of the region.
+    fn is_visible_frame(&self) -> bool {
+        match self {
+            Self::Frame { frame, .. } => {
+                !frame.size().is_zero()
+                    || frame.items().any(|(_, item)| !matches!(item, FrameItem::Meta(..)))
+            }
+            _ => false,
+        }
+    }
 }
 
 impl<'a> FlowLayouter<'a> {
@@ -207,11 +222,15 @@ impl<'a> FlowLayouter<'a> {
         styles: StyleChain,
     ) -> SourceResult<()> {
         let align = AlignElem::alignment_in(styles).resolve(styles);
-        let leading = ParElem::leading_in(styles);
         let consecutive = self.last_was_par;
-        let lines = par
-            .layout(vt, styles, consecutive, self.regions.base(), self.regions.expand.x)?
-            .into_frames();
+        let (lines, leadings) = par.layout(
+            vt,
+            styles,
+            consecutive,
+            self.regions.base(),
+            self.regions.expand.x,
+        )?;
+        let lines = lines.into_frames();
 
         let mut sticky = self.items.len();
         for (i, item) in self.items.iter().enumerate().rev() {
@@ -234,7 +253,7 @@ impl<'a> FlowLayouter<'a> {
 
         for (i, frame) in lines.into_iter().enumerate() {
             if i > 0 {
-                self.layout_item(vt, FlowItem::Absolute(leading, true))?;
+                self.layout_item(vt, FlowItem::Absolute(leadings[i - 1], true))?;
             }
 
             self.layout_item(
@@ -347,11 +366,18 @@ impl<'a> FlowLayouter<'a> {
     fn layout_item(&mut self, vt: &mut Vt, mut item: FlowItem) -> SourceResult<()> {
         match item {
             FlowItem::Absolute(v, weak) => {
+                // Invisible frames only become transparent to spacing after
+                // a region break. At the start of the flow, the original
+                // content already decided which spacing to keep.
+                let first = self.finished.is_empty();
                 if weak
-                    && !self
-                        .items
-                        .iter()
-                        .any(|item| matches!(item, FlowItem::Frame { .. }))
+                    && !self.items.iter().any(|item| {
+                        if first {
+                            matches!(item, FlowItem::Frame { .. })
+                        } else {
+                            item.is_visible_frame()
+                        }
+                    })
                 {
                     return Ok(());
                 }
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use crate::text::TextElem;
 
-use super::Sizing;
+use super::{AlignElem, Celled, Sizing};
 
 /// Arranges content in a grid.
 ///
@@ -29,6 +29,11 @@ use super::Sizing;
 ///   with a fraction of `{1fr}`, they will each take up half of the remaining
 ///   space.
 ///
+/// - A dictionary with the keys `min` and `max` (e.g. `{(min: 1cm)}`): The
+///   track will be sized like an `{auto}` track, but is at least `min` and at
+///   most `max` large. Both keys are optional. A row that breaks across
+///   multiple pages or regions is not bounded.
+///
 /// To specify a single track, the array can be omitted in favor of a single
 /// value. To specify multiple `{auto}` tracks, enter the number of tracks
 /// instead of an array. For example, `columns:` `{3}` is equivalent to
@@ -58,14 +63,34 @@ use super::Sizing;
 ///   cell[One more thing...],
 /// )
 /// ```
-#[elem(Layout)]
+///
+/// # Lines
+/// Individual rules can be drawn between the tracks of a grid or a
+/// [table]($table) by placing [`grid.hline`]($grid.hline) and
+/// [`grid.vline`]($grid.vline) elements among the cells. They don't occupy a
+/// cell themselves and are drawn on top of the cells and the table's
+/// [stroke]($table.stroke).
+///
+/// ```example
+/// #table(
+///   columns: 3,
+///   stroke: none,
+///   [*Name*], [*Age*], [*City*],
+///   grid.hline(y: 1, stroke: 1pt),
+///   grid.vline(x: 1, start: 1, stroke: 0.5pt + gray),
+///   [Alice], [30], [Berlin],
+///   [Bob], [25], [Paris],
+/// )
+/// ```
+#[elem(scope, Layout)]
 pub struct GridElem {
     /// The column sizes.
     ///
     /// Either specify a track size array or provide an integer to create a grid
     /// with that many `{auto}`-sized columns. Note that opposed to rows and
     /// gutters, providing a single track size will only ever create a single
-    /// column.
+    /// column. If there are more cells than columns, the remaining cells wrap
+    /// around into a new row.
     pub columns: TrackSizings,
 
     /// The row sizes.
@@ -91,6 +116,37 @@ pub struct GridElem {
     #[parse(args.named("row-gutter")?.or_else(|| gutter.clone()))]
     pub row_gutter: TrackSizings,
 
+    /// How to fill the gaps between rows and columns.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: (20pt,) * 3,
+    ///   rows: 20pt,
+    ///   gutter: 3pt,
+    ///   gutter-fill: luma(200),
+    ///   ..range(9).map(str)
+    /// )
+    /// ```
+    pub gutter_fill: Option<Paint>,
+
+    /// How to align the cells' content.
+    ///
+    /// This can either be a single alignment, an array of alignments
+    /// (corresponding to each column and cycled if there are more columns than
+    /// alignments) or a function that returns an alignment. The function is
+    /// passed the cells' column and row index, starting at zero. If set to
+    /// `{auto}`, the outer alignment is used.
+    ///
+    /// ```example
+    /// #grid(
+    ///   columns: (60pt, 60pt),
+    ///   align: (left, right),
+    ///   [Apples], [3],
+    ///   [Pears], [12],
+    /// )
+    /// ```
+    pub align: Celled<Smart<Align>>,
+
     /// The contents of the grid cells.
     ///
     /// The cells are populated in row-major order.
@@ -98,6 +154,15 @@ pub struct GridElem {
     pub children: Vec<Content>,
 }
 
+#[scope]
+impl GridElem {
+    #[elem]
+    type GridHLine;
+
+    #[elem]
+    type GridVLine;
+}
+
 impl Layout for GridElem {
     #[tracing::instrument(name = "GridElem::layout", skip_all)]
     fn layout(
@@ -107,7 +172,24 @@ impl Layout for GridElem {
         regions: Regions,
     ) -> SourceResult<Fragment> {
         // Prepare grid layout by unifying content and gutter tracks.
-        let cells = self.children();
+        let align = self.align(styles);
+        let cols = self.columns(styles).0.len().max(1);
+        let (lines, cells): (Vec<_>, Vec<_>) =
+            self.children().into_iter().partition(is_grid_line);
+        let cells: Vec<_> = cells
+            .into_iter()
+            .enumerate()
+            .map(|(i, child)| {
+                let (x, y) = (i % cols, i / cols);
+                Ok(match align.resolve(vt, x, y)? {
+                    Smart::Custom(alignment) => {
+                        child.styled(AlignElem::set_alignment(alignment))
+                    }
+                    Smart::Auto => child,
+                })
+            })
+            .collect::<SourceResult<_>>()?;
+
         let layouter = GridLayouter::new(
             Axes::new(&self.columns(styles).0, &self.rows(styles).0),
             Axes::new(&self.column_gutter(styles).0, &self.row_gutter(styles).0),
@@ -117,7 +199,197 @@ impl Layout for GridElem {
         );
 
         // Measure the columns and layout the grid row-by-row.
-        Ok(layouter.layout(vt)?.fragment)
+        let mut layout = layouter.layout(vt)?;
+        fill_gutters(&mut layout, self.gutter_fill(styles), self.span());
+        draw_grid_lines(&mut layout, &lines, styles);
+        Ok(layout.fragment)
+    }
+}
+
+/// A horizontal line in a grid or table.
+///
+/// The line is drawn between two rows and spans a range of columns. It is
+/// drawn on top of the cells and the table's stroke.
+///
+/// ```example
+/// #grid(
+///   columns: 3,
+///   gutter: 5pt,
+///   [A], [B], [C],
+///   grid.hline(y: 1, start: 1),
+///   [D], [E], [F],
+/// )
+/// ```
+#[elem(name = "hline", title = "Grid Horizontal Line")]
+pub struct GridHLine {
+    /// The row above which the line is drawn. A value equal to the number of
+    /// rows places the line below the last row.
+    pub y: usize,
+
+    /// The column at which the line starts.
+    pub start: usize,
+
+    /// The column before which the line ends. If `{none}`, the line extends
+    /// to the end of the grid.
+    pub end: Option<usize>,
+
+    /// How to stroke the line.
+    #[resolve]
+    #[fold]
+    #[default(Some(Stroke::default()))]
+    pub stroke: Option<Stroke>,
+}
+
+/// A vertical line in a grid or table.
+///
+/// The line is drawn between two columns and spans a range of rows. It is
+/// drawn on top of the cells and the table's stroke.
+///
+/// ```example
+/// #grid(
+///   columns: 3,
+///   column-gutter: 5pt,
+///   [A], [B], [C],
+///   grid.vline(x: 2, end: 1),
+///   [D], [E], [F],
+/// )
+/// ```
+#[elem(name = "vline", title = "Grid Vertical Line")]
+pub struct GridVLine {
+    /// The column before which the line is drawn. A value equal to the number
+    /// of columns places the line after the last column.
+    pub x: usize,
+
+    /// The row at which the line starts.
+    pub start: usize,
+
+    /// The row before which the line ends. If `{none}`, the line extends to
+    /// the end of the grid.
+    pub end: Option<usize>,
+
+    /// How to stroke the line.
+    #[resolve]
+    #[fold]
+    #[default(Some(Stroke::default()))]
+    pub stroke: Option<Stroke>,
+}
+
+/// Whether a grid child is a line instead of a cell.
+pub(super) fn is_grid_line(child: &Content) -> bool {
+    child.is::<GridHLine>() || child.is::<GridVLine>()
+}
+
+/// Fill the gutter tracks of a laid out grid below its contents.
+pub(super) fn fill_gutters(layout: &mut GridLayout, fill: Option<Paint>, span: Span) {
+    let Some(fill) = fill else { return };
+    if !layout.has_gutter || layout.cols.is_empty() {
+        return;
+    }
+
+    // With gutters, the tracks at odd indices are gutter tracks. As gutter
+    // tracks alternate with content tracks, this also holds when the columns
+    // are reversed for right-to-left grids.
+    for (frame, rows) in layout.fragment.iter_mut().zip(&layout.rows) {
+        let mut dx = Abs::zero();
+        for (x, &col) in layout.cols.iter().enumerate() {
+            let mut dy = Abs::zero();
+            for row in rows {
+                if x % 2 == 1 || row.y % 2 == 1 {
+                    let pos = Point::new(dx, dy);
+                    let size = Size::new(col, row.height);
+                    let rect = Geometry::Rect(size).filled(fill.clone());
+                    frame.prepend(pos, FrameItem::Shape(rect, span));
+                }
+                dy += row.height;
+            }
+            dx += col;
+        }
+    }
+}
+
+/// Draw the [`GridHLine`] and [`GridVLine`] elements among a grid's children
+/// on top of its laid out frames.
+pub(super) fn draw_grid_lines(
+    layout: &mut GridLayout,
+    lines: &[Content],
+    styles: StyleChain,
+) {
+    if lines.is_empty() || layout.cols.is_empty() {
+        return;
+    }
+
+    // With gutters, content track `k` sits at track index `2k`. A line at
+    // content boundary `k` is drawn directly after content track `k - 1`.
+    let step = if layout.has_gutter { 2 } else { 1 };
+    let boundary = |k: usize| if k == 0 { 0 } else { (k - 1) * step + 1 };
+
+    // Column offsets, indexed by track boundary.
+    let ncols = layout.cols.len();
+    let mut xs = vec![Abs::zero()];
+    for &col in &layout.cols {
+        xs.push(*xs.last().unwrap() + col);
+    }
+
+    let col_x = |t: usize| {
+        let t = t.min(ncols);
+        xs[if layout.is_rtl { ncols - t } else { t }]
+    };
+
+    // The number of row tracks, to recognize the grid's bottom edge.
+    let nrows = layout.rows.iter().flatten().map(|piece| piece.y + 1).max();
+
+    for (frame, rows) in layout.fragment.iter_mut().zip(&layout.rows) {
+        // The vertical offsets of the row pieces in this region.
+        let mut pieces = vec![];
+        let mut bottom = Abs::zero();
+        for piece in rows {
+            pieces.push((piece.y, bottom, bottom + piece.height));
+            bottom += piece.height;
+        }
+
+        // The offset of a row boundary in this region, if it's contained in it.
+        let row_y = |t: usize| match pieces.iter().find(|&&(y, ..)| y == t) {
+            Some(&(_, top, _)) => Some(top),
+            None => pieces
+                .last()
+                .filter(|&&(y, ..)| Some(t) == nrows && Some(y + 1) == nrows)
+                .map(|_| bottom),
+        };
+
+        for line in lines {
+            if let Some(hline) = line.to::<GridHLine>() {
+                let stroke = hline.stroke(styles).map(Stroke::unwrap_or_default);
+                let Some(stroke) = stroke else { continue };
+                let Some(y) = row_y(boundary(hline.y(styles))) else { continue };
+
+                let end = hline.end(styles).unwrap_or((ncols + step - 1) / step);
+                let (a, b) = (col_x(hline.start(styles) * step), col_x(boundary(end)));
+                let (x0, x1) = (a.min(b), a.max(b));
+                if x0 < x1 {
+                    let shape = Geometry::Line(Point::with_x(x1 - x0)).stroked(stroke);
+                    let pos = Point::new(x0, y);
+                    frame.push(pos, FrameItem::Shape(shape, hline.span()));
+                }
+            } else if let Some(vline) = line.to::<GridVLine>() {
+                let stroke = vline.stroke(styles).map(Stroke::unwrap_or_default);
+                let Some(stroke) = stroke else { continue };
+
+                // The row pieces in this region that the line spans.
+                let start = vline.start(styles) * step;
+                let end = vline.end(styles).map_or(usize::MAX, boundary);
+                let spanned: Vec<_> =
+                    pieces.iter().filter(|&&(y, ..)| y >= start && y < end).collect();
+                let (Some(&&(_, y0, _)), Some(&&(.., y1))) =
+                    (spanned.first(), spanned.last())
+                else {
+                    continue;
+                };
+
+                let shape = Geometry::Line(Point::with_y(y1 - y0)).stroked(stroke);
+                let pos = Point::new(col_x(boundary(vline.x(styles))), y0);
+                frame.push(pos, FrameItem::Shape(shape, vline.span()));
+            }
+        }
     }
 }
 
@@ -172,6 +444,10 @@ pub struct GridLayout {
     pub cols: Vec<Abs>,
     /// The heights of the resulting rows segments, by region.
     pub rows: Vec<Vec<RowPiece>>,
+    /// Whether the grid has gutter tracks in between its content tracks.
+    pub has_gutter: bool,
+    /// Whether the columns are ordered right-to-left.
+    pub is_rtl: bool,
 }
 
 /// Details about a resulting row piece.
@@ -287,9 +563,16 @@ impl<'a> GridLayouter<'a> {
             }
 
             match self.rows[y] {
-                Sizing::Auto => self.layout_auto_row(vt, y)?,
+                Sizing::Auto => self.layout_auto_row(vt, y, None)?,
                 Sizing::Rel(v) => self.layout_relative_row(vt, v, y)?,
                 Sizing::Fr(v) => self.lrows.push(Row::Fr(v, y)),
+                Sizing::Bounded { min, max } => {
+                    let base = self.regions.base().y;
+                    let resolve =
+                        |v: Rel<Length>| v.resolve(self.styles).relative_to(base);
+                    let bounds = (resolve(min), max.map(resolve).unwrap_or(Abs::inf()));
+                    self.layout_auto_row(vt, y, Some(bounds))?
+                }
             }
         }
 
@@ -299,6 +582,8 @@ impl<'a> GridLayouter<'a> {
             fragment: Fragment::frames(self.finished),
             cols: self.rcols,
             rows: self.rrows,
+            has_gutter: self.has_gutter,
+            is_rtl: self.is_rtl,
         })
     }
 
@@ -315,7 +600,7 @@ impl<'a> GridLayouter<'a> {
         // fractional tracks.
         for (&col, rcol) in self.cols.iter().zip(&mut self.rcols) {
             match col {
-                Sizing::Auto => {}
+                Sizing::Auto | Sizing::Bounded { .. } => {}
                 Sizing::Rel(v) => {
                     let resolved =
                         v.resolve(self.styles).relative_to(self.regions.base().x);
@@ -360,7 +645,7 @@ impl<'a> GridLayouter<'a> {
         // Determine size of auto columns by laying out all cells in those
         // columns, measuring them and finding the largest one.
         for (x, &col) in self.cols.iter().enumerate() {
-            if col != Sizing::Auto {
+            if !col.is_auto() {
                 continue;
             }
 
@@ -383,6 +668,15 @@ impl<'a> GridLayouter<'a> {
                 }
             }
 
+            if let Sizing::Bounded { min, max } = col {
+                let base = self.regions.base().x;
+                let resolve = |v: Rel<Length>| v.resolve(self.styles).relative_to(base);
+                resolved.set_max(resolve(min));
+                if let Some(max) = max {
+                    resolved.set_min(resolve(max));
+                }
+            }
+
             self.rcols[x] = resolved;
             auto += resolved;
             count += 1;
@@ -421,7 +715,7 @@ impl<'a> GridLayouter<'a> {
             for (&col, &rcol) in self.cols.iter().zip(&self.rcols) {
                 // Remove an auto column if it is not overlarge (rcol <= fair),
                 // but also hasn't already been removed (rcol > last).
-                if col == Sizing::Auto && rcol <= fair && rcol > last {
+                if col.is_auto() && rcol <= fair && rcol > last {
                     redistribute -= rcol;
                     overlarge -= 1;
                     changed = true;
@@ -431,15 +725,21 @@ impl<'a> GridLayouter<'a> {
 
         // Redistribute space fairly among overlarge columns.
         for (&col, rcol) in self.cols.iter().zip(&mut self.rcols) {
-            if col == Sizing::Auto && *rcol > fair {
+            if col.is_auto() && *rcol > fair {
                 *rcol = fair;
             }
         }
     }
 
     /// Layout a row with automatic height. Such a row may break across multiple
-    /// regions.
-    fn layout_auto_row(&mut self, vt: &mut Vt, y: usize) -> SourceResult<()> {
+    /// regions. If the row is bounded by a minimum and maximum height and fits
+    /// into a single region, its height is clamped to these bounds.
+    fn layout_auto_row(
+        &mut self,
+        vt: &mut Vt,
+        y: usize,
+        bounds: Option<(Abs, Abs)>,
+    ) -> SourceResult<()> {
         // Determine the size for each region of the row. If the first region
         // ends up empty for some column, skip the region and remeasure.
         let mut resolved = match self.measure_auto_row(vt, y, true)? {
@@ -450,6 +750,13 @@ impl<'a> GridLayouter<'a> {
             }
         };
 
+        if let Some((min, max)) = bounds {
+            if resolved.len() <= 1 {
+                let height = resolved.first().copied().unwrap_or_default();
+                return self.layout_fixed_row(vt, height.max(min).min(max), y);
+            }
+        }
+
         // Nothing to layout.
         if resolved.is_empty() {
             return Ok(());
@@ -538,7 +845,17 @@ impl<'a> GridLayouter<'a> {
         y: usize,
     ) -> SourceResult<()> {
         let resolved = v.resolve(self.styles).relative_to(self.regions.base().y);
-        let frame = self.layout_single_row(vt, resolved, y)?;
+        self.layout_fixed_row(vt, resolved, y)
+    }
+
+    /// Layout a row with an already determined height.
+    fn layout_fixed_row(
+        &mut self,
+        vt: &mut Vt,
+        height: Abs,
+        y: usize,
+    ) -> SourceResult<()> {
+        let frame = self.layout_single_row(vt, height, y)?;
 
         // Skip to fitting region.
         let height = frame.height();
@@ -570,7 +887,7 @@ impl<'a> GridLayouter<'a> {
             if let Some(cell) = self.cell(x, y) {
                 let size = Size::new(rcol, height);
                 let mut pod = Regions::one(size, Axes::splat(true));
-                if self.rows[y] == Sizing::Auto {
+                if self.rows[y].is_auto() {
                     pod.full = self.regions.full;
                 }
                 let frame = cell.layout(vt, self.styles, pod)?.into_frame();
@@ -703,3 +1020,91 @@ impl<'a> GridLayouter<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hline_is_drawn_between_rows() {
+        let size = Size::new(Abs::pt(60.0), Abs::pt(30.0));
+        let mut layout = GridLayout {
+            fragment: Fragment::frame(Frame::new(size)),
+            cols: vec![Abs::pt(20.0); 3],
+            rows: vec![vec![
+                RowPiece { height: Abs::pt(10.0), y: 0 },
+                RowPiece { height: Abs::pt(20.0), y: 1 },
+            ]],
+            has_gutter: false,
+            is_rtl: false,
+        };
+
+        let hline = GridHLine::new().with_y(1).with_start(1).with_end(Some(3)).pack();
+        let styles = Styles::new();
+        draw_grid_lines(&mut layout, &[hline], StyleChain::new(&styles));
+
+        let frame = layout.fragment.into_frame();
+        let lines: Vec<_> = frame
+            .items()
+            .filter_map(|(pos, item)| match item {
+                FrameItem::Shape(Shape { geometry: Geometry::Line(target), .. }, _) => {
+                    Some((*pos, *target))
+                }
+                _ => None,
+            })
+            .collect();
+
+        // The rule sits below the first row and spans the second and third
+        // columns.
+        assert_eq!(
+            lines,
+            [(Point::new(Abs::pt(20.0), Abs::pt(10.0)), Point::with_x(Abs::pt(40.0)))]
+        );
+    }
+
+    #[test]
+    fn test_gutter_fill_covers_only_gaps() {
+        let size = Size::new(Abs::pt(45.0), Abs::pt(25.0));
+        let mut layout = GridLayout {
+            fragment: Fragment::frame(Frame::new(size)),
+            cols: vec![Abs::pt(20.0), Abs::pt(5.0), Abs::pt(20.0)],
+            rows: vec![vec![
+                RowPiece { height: Abs::pt(10.0), y: 0 },
+                RowPiece { height: Abs::pt(5.0), y: 1 },
+                RowPiece { height: Abs::pt(10.0), y: 2 },
+            ]],
+            has_gutter: true,
+            is_rtl: false,
+        };
+
+        fill_gutters(&mut layout, Some(Color::BLACK.into()), Span::detached());
+
+        let frame = layout.fragment.into_frame();
+        let mut rects: Vec<_> = frame
+            .items()
+            .filter_map(|(pos, item)| match item {
+                FrameItem::Shape(Shape { geometry: Geometry::Rect(size), .. }, _) => {
+                    Some((
+                        (pos.x.to_pt(), pos.y.to_pt()),
+                        (size.x.to_pt(), size.y.to_pt()),
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+        rects.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // The gutter column between the two content columns and the gutter
+        // row between the two content rows, without overlap.
+        assert_eq!(
+            rects,
+            [
+                ((0.0, 10.0), (20.0, 5.0)),
+                ((20.0, 0.0), (5.0, 10.0)),
+                ((20.0, 10.0), (5.0, 5.0)),
+                ((20.0, 15.0), (5.0, 10.0)),
+                ((25.0, 10.0), (20.0, 5.0)),
+            ]
+        );
+    }
+}
@@ -80,11 +80,32 @@ pub struct ListElem {
     #[default(ListMarker::Content(vec![TextElem::packed('•')]))]
     pub marker: ListMarker,
 
-    /// The indent of each item.
+    /// How to align the marker vertically relative to the item's body.
+    ///
+    /// By default, the marker sits at the top of the item, which puts it on
+    /// the baseline of the body's first line, even if the item spans multiple
+    /// lines or paragraphs.
+    ///
+    /// ```example
+    /// #set list(marker-align: horizon)
+    /// - A marker centered next to
+    ///   an item of two lines.
+    /// ```
+    pub marker_align: VAlign,
+
+    /// The indent of each item. This is the space before the marker, so
+    /// increasing it moves both the marker and the body.
     #[resolve]
     pub indent: Length,
 
-    /// The spacing between the marker and the body of each item.
+    /// The spacing between the marker and the body of each item. Increasing
+    /// it moves the body, but leaves the marker in place.
+    ///
+    /// ```example
+    /// #set list(indent: 1em, body-indent: 1.5em)
+    /// - Indented
+    /// - Spaced out
+    /// ```
     #[resolve]
     #[default(Em::new(0.5).into())]
     pub body_indent: Length,
@@ -141,7 +162,7 @@ impl Layout for ListElem {
             .marker(styles)
             .resolve(vt, depth)?
             // avoid '#set align' interference with the list
-            .aligned(HAlign::Start + VAlign::Top);
+            .aligned(HAlign::Start + self.marker_align(styles));
 
         let mut cells = vec![];
         for item in self.children() {
@@ -8,6 +8,9 @@ use crate::prelude::*;
 /// want to measure in the current layout dimensions, you can combine `measure`
 /// and [`layout`]($layout).
 ///
+/// Measuring has no side effects: Counters or states that are updated within
+/// the measured content are not affected by the measurement.
+///
 /// # Example
 /// The same content can have a different size depending on the styles that
 /// are active when it is layouted. For example, in the example below
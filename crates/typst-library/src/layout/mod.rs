@@ -178,7 +178,11 @@ pub trait Layout {
             tracer: TrackedMut::reborrow_mut(&mut vt.tracer),
             delayed: TrackedMut::reborrow_mut(&mut vt.delayed),
         };
-        self.layout(&mut vt, styles, regions)
+
+        // Content is usually measured speculatively, so paragraphs shouldn't
+        // warn about lines that don't fit.
+        let local = Styles::from(ParElem::set_measuring(true));
+        self.layout(&mut vt, styles.chain(&local), regions)
     }
 }
 
@@ -288,6 +292,10 @@ fn realize_block<'a>(
     Ok((FlowElem::new(children.to_vec()).pack(), shared))
 }
 
+/// How many show rules may be applied on top of each other's output before
+/// realization is aborted.
+const MAX_SHOW_RULE_DEPTH: usize = 256;
+
 /// Builds a document or a flow element from content.
 struct Builder<'a, 'v, 't> {
     /// The virtual typesetter.
@@ -302,6 +310,9 @@ struct Builder<'a, 'v, 't> {
     par: ParBuilder<'a>,
     /// The current list building state.
     list: ListBuilder<'a>,
+    /// How many realized elements are currently being built on top of each
+    /// other.
+    depth: usize,
 }
 
 /// Temporary storage arenas for building.
@@ -322,6 +333,7 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
             flow: FlowBuilder::default(),
             par: ParBuilder::default(),
             list: ListBuilder::default(),
+            depth: 0,
         }
     }
 
@@ -336,8 +348,18 @@ impl<'a, 'v, 't> Builder<'a, 'v, 't> {
         }
 
         if let Some(realized) = realize(self.vt, content, styles)? {
+            // A show rule that produces content matching itself would
+            // otherwise recurse until the stack overflows.
+            if self.depth >= MAX_SHOW_RULE_DEPTH {
+                bail!(error!(content.span(), "maximum show rule depth exceeded")
+                    .with_hint("check whether a show rule produces its own target"));
+            }
+
+            self.depth += 1;
             let stored = self.scratch.content.alloc(realized);
-            return self.accept(stored, styles);
+            let result = self.accept(stored, styles);
+            self.depth -= 1;
+            return result;
         }
 
         if let Some((elem, local)) = content.to_styled() {
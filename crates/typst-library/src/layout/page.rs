@@ -3,7 +3,7 @@ use std::str::FromStr;
 
 use typst::eval::AutoValue;
 
-use super::{AlignElem, ColumnsElem};
+use super::{AlignElem, ColumnsElem, Spacing};
 use crate::meta::{Counter, CounterKey, ManualPageCounter, Numbering};
 use crate::prelude::*;
 use crate::text::TextElem;
@@ -95,6 +95,10 @@ pub struct PageElem {
     /// - `{auto}`: The margins are set automatically to 2.5/21 times the smaller
     ///   dimension of the page. This results in 2.5cm margins for an A4 page.
     /// - A single length: The same margin on all sides.
+    /// - A [fraction]($fraction): The margins share the space that the content
+    ///   leaves free on their axis. Along such an axis, the content is laid out
+    ///   at its natural size instead of filling the page. For instance,
+    ///   `{(x: 1fr)}` centers the content horizontally.
     /// - A dictionary: With a dictionary, the margins can be set individually.
     ///   The dictionary can contain the following keys in order of precedence:
     ///   - `top`: The top margin.
@@ -143,7 +147,8 @@ pub struct PageElem {
     /// How many columns the page has.
     ///
     /// If you need to insert columns into a page or other container, you can
-    /// also use the [`columns` function]($columns).
+    /// also use the [`columns` function]($columns). To balance the columns on
+    /// the last page, use `{set columns(balance: true)}`.
     ///
     /// ```example:single
     /// #set page(columns: 2, height: 4.8cm)
@@ -167,12 +172,16 @@ pub struct PageElem {
     /// environmentally friendly and cost-effective to source pre-dyed pages and
     /// not set this property.
     ///
+    /// When set to `{auto}`, the page is white. When set to `{none}`, the
+    /// page is transparent, which carries through to PNG export and lets
+    /// the page be composited onto other backgrounds.
+    ///
     /// ```example
     /// #set page(fill: rgb("444352"))
     /// #set text(fill: rgb("fdfdfd"))
     /// *Dark mode enabled.*
     /// ```
-    pub fill: Option<Paint>,
+    pub fill: Smart<Option<Paint>>,
 
     /// How to [number]($numbering) the pages.
     ///
@@ -349,12 +358,24 @@ impl PageElem {
         let default = Rel::<Length>::from((2.5 / 21.0) * min);
         let margin = self.margin(styles);
         let two_sided = margin.two_sided.unwrap_or(false);
-        let margin = margin
+        let sides = margin
             .sides
-            .map(|side| side.and_then(Smart::as_custom).unwrap_or(default))
+            .map(|side| side.and_then(Smart::as_custom).unwrap_or(Spacing::Rel(default)));
+        let margin = sides
+            .map(|side| match side {
+                Spacing::Rel(rel) => rel,
+                Spacing::Fr(_) => Rel::zero(),
+            })
             .resolve(styles)
             .relative_to(size);
 
+        // Fractional margins share the space the content leaves free.
+        let fr = sides.map(|side| match side {
+            Spacing::Rel(_) => Fr::zero(),
+            Spacing::Fr(fr) => fr,
+        });
+        let fr_sum = Axes::new(fr.left + fr.right, fr.top + fr.bottom);
+
         // Determine the binding.
         let binding =
             self.binding(styles)
@@ -371,7 +392,8 @@ impl PageElem {
         }
 
         let area = size - margin.sum_by_axis();
-        let mut regions = Regions::repeat(area, area.map(Abs::is_finite));
+        let expand = area.zip_map(fr_sum, |v, fr| v.is_finite() && fr.is_zero());
+        let mut regions = Regions::repeat(area, expand);
         regions.root = true;
 
         // Layout the child.
@@ -384,7 +406,7 @@ impl PageElem {
             frames.push(Frame::new(size));
         }
 
-        let fill = self.fill(styles);
+        let fill = self.fill(styles).unwrap_or_else(|| Some(Color::WHITE.into()));
         let foreground = self.foreground(styles);
         let background = self.background(styles);
         let header_ascent = self.header_ascent(styles);
@@ -427,10 +449,18 @@ impl PageElem {
             // The padded width of the page's content without margins.
             let pw = frame.width();
 
+            // Distribute the space the content leaves free to fractional
+            // margins.
+            let mut margin = margin;
+            let free = area - frame.size();
+            margin.left += fr.left.share(fr_sum.x, free.x);
+            margin.right += fr.right.share(fr_sum.x, free.x);
+            margin.top += fr.top.share(fr_sum.y, free.y);
+            margin.bottom += fr.bottom.share(fr_sum.y, free.y);
+
             // If two sided, left becomes inside and right becomes outside.
             // Thus, for left-bound pages, we want to swap on even pages and
             // for right-bound pages, we want to swap on odd pages.
-            let mut margin = margin;
             if two_sided && binding.swap(page_counter.physical()) {
                 std::mem::swap(&mut margin.left, &mut margin.right);
             }
@@ -509,7 +539,7 @@ impl PageElem {
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Margin {
     /// The margins for each side.
-    pub sides: Sides<Option<Smart<Rel<Length>>>>,
+    pub sides: Sides<Option<Smart<Spacing>>>,
     /// Whether to swap `left` and `right` to make them `inside` and `outside`
     /// (when to swap depends on the binding).
     pub two_sided: Option<bool>,
@@ -517,7 +547,7 @@ pub struct Margin {
 
 impl Margin {
     /// Create an instance with four equal components.
-    pub fn splat(value: Option<Smart<Rel<Length>>>) -> Self {
+    pub fn splat(value: Option<Smart<Spacing>>) -> Self {
         Self { sides: Sides::splat(value), two_sided: None }
     }
 }
@@ -526,13 +556,7 @@ impl Fold for Margin {
     type Output = Margin;
 
     fn fold(self, outer: Self::Output) -> Self::Output {
-        let sides =
-            self.sides
-                .zip(outer.sides)
-                .map(|(inner, outer)| match (inner, outer) {
-                    (Some(value), Some(outer)) => Some(value.fold(outer)),
-                    _ => inner.or(outer),
-                });
+        let sides = self.sides.zip(outer.sides).map(|(inner, outer)| inner.or(outer));
         let two_sided = self.two_sided.or(outer.two_sided);
         Margin { sides, two_sided }
     }
@@ -562,7 +586,7 @@ cast! {
         Value::Dict(dict)
     },
     _: AutoValue => Self::splat(Some(Smart::Auto)),
-    v: Rel<Length> => Self::splat(Some(Smart::Custom(v))),
+    v: Spacing => Self::splat(Some(Smart::Custom(v))),
     mut dict: Dict => {
         let mut take = |key| dict.take(key).ok().map(Value::cast).transpose();
 
@@ -688,7 +712,7 @@ cast! {
 #[elem(title = "Page Break")]
 pub struct PagebreakElem {
     /// If `{true}`, the page break is skipped if the current page is already
-    /// empty.
+    /// empty. Multiple adjacent weak page breaks thus collapse into one.
     #[default(false)]
     pub weak: bool,
 
@@ -6,6 +6,7 @@ use icu_provider_adapters::fork::ForkByKeyProvider;
 use icu_provider_blob::BlobDataProvider;
 use icu_segmenter::{LineBreakIteratorUtf8, LineSegmenter};
 use once_cell::sync::Lazy;
+use typst::diag::warning;
 use typst::eval::Tracer;
 use typst::model::DelayedErrors;
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
@@ -17,7 +18,7 @@ use crate::math::EquationElem;
 use crate::prelude::*;
 use crate::text::{
     is_gb_style, shape, LinebreakElem, Quoter, Quotes, ShapedText, SmartquoteElem,
-    SpaceElem, TextElem,
+    SpaceElem, TextDir, TextElem, TextSize,
 };
 
 /// Arranges text, spacing and inline-level elements into a paragraph.
@@ -48,6 +49,14 @@ use crate::text::{
 #[elem(title = "Paragraph", Construct)]
 pub struct ParElem {
     /// The spacing between lines.
+    ///
+    /// Font-relative leading is resolved relative to the largest text in the
+    /// line above, so that lines with larger inline text are spaced further
+    /// apart. It never shrinks below the leading for the paragraph's font
+    /// size.
+    ///
+    /// The leading may be negative to pull lines closer together than their
+    /// height, but a line is never moved above the top of the line before it.
     #[resolve]
     #[default(Em::new(0.65).into())]
     pub leading: Length,
@@ -61,6 +70,13 @@ pub struct ParElem {
     /// Note that the current [alignment]($align) still has an effect on the
     /// placement of the last line except if it ends with a
     /// [justified line break]($linebreak.justify).
+    ///
+    /// When justifying, spaces may grow by up to half and shrink by up to a
+    /// third of their natural width. With the
+    /// [optimized line breaker]($par.linebreaks), lines that would need more
+    /// stretching than that are penalized heavily, so that breaking a line
+    /// earlier or hyphenating a word is preferred. Lines are only shrunk
+    /// within the limit.
     #[default(false)]
     pub justify: bool,
 
@@ -69,7 +85,9 @@ pub struct ParElem {
     /// When this property is set to `{auto}`, its default value, optimized line
     /// breaks will be used for justified paragraphs. Enabling optimized line
     /// breaks for ragged paragraphs may also be worthwhile to improve the
-    /// appearance of the text.
+    /// appearance of the text. If [hyphenation]($text.hyphenate) is enabled,
+    /// the optimized line breaker also considers all hyphenation points, at a
+    /// small extra [cost]($text.costs) for each hyphen.
     ///
     /// ```example
     /// #set page(width: 207pt)
@@ -89,20 +107,36 @@ pub struct ParElem {
 
     /// The indent the first line of a paragraph should have.
     ///
-    /// Only the first line of a consecutive paragraph will be indented (not
-    /// the first one in a block or on the page).
+    /// By default, only the first line of a consecutive paragraph will be
+    /// indented (not the first one in a block, on the page, or after a
+    /// heading). To indent all paragraphs, pass a dictionary with the keys
+    /// `amount` for the indent and `all` set to `{true}`.
     ///
     /// By typographic convention, paragraph breaks are indicated either by some
     /// space between paragraphs or by indented first lines. Consider reducing
     /// the [paragraph spacing]($block.spacing) to the [`leading`] when
     /// using this property (e.g. using
     /// `[#show par: set block(spacing: 0.65em)]`).
-    pub first_line_indent: Length,
+    ///
+    /// ```example
+    /// #set par(first-line-indent: (amount: 1em, all: true))
+    /// = Introduction
+    /// Even this paragraph is indented.
+    ///
+    /// And so is this one.
+    /// ```
+    pub first_line_indent: FirstLineIndent,
 
     /// The indent all but the first line of a paragraph should have.
     #[resolve]
     pub hanging_indent: Length,
 
+    /// Whether the paragraph is only measured. Measured paragraphs don't warn
+    /// about lines that don't fit.
+    #[internal]
+    #[default(false)]
+    pub measuring: bool,
+
     /// The contents of the paragraph.
     #[external]
     #[required]
@@ -131,6 +165,9 @@ impl Construct for ParElem {
 
 impl ParElem {
     /// Layout the paragraph into a collection of lines.
+    ///
+    /// Also returns the leading to insert below each of the lines but the
+    /// last one.
     #[tracing::instrument(name = "ParElement::layout", skip_all)]
     pub fn layout(
         &self,
@@ -139,7 +176,7 @@ impl ParElem {
         consecutive: bool,
         region: Size,
         expand: bool,
-    ) -> SourceResult<Fragment> {
+    ) -> SourceResult<(Fragment, Vec<Abs>)> {
         #[comemo::memoize]
         #[allow(clippy::too_many_arguments)]
         fn cached(
@@ -153,7 +190,7 @@ impl ParElem {
             consecutive: bool,
             region: Size,
             expand: bool,
-        ) -> SourceResult<Fragment> {
+        ) -> SourceResult<(Fragment, Vec<Abs>)> {
             let mut locator = Locator::chained(locator);
             let mut vt = Vt {
                 world,
@@ -179,7 +216,7 @@ impl ParElem {
             finalize(&mut vt, &p, &lines, region, expand)
         }
 
-        let fragment = cached(
+        let (fragment, leadings) = cached(
             self,
             vt.world,
             vt.introspector,
@@ -193,7 +230,7 @@ impl ParElem {
         )?;
 
         vt.locator.visit_frames(&fragment);
-        Ok(fragment)
+        Ok((fragment, leadings))
     }
 }
 
@@ -209,6 +246,27 @@ pub enum Linebreaks {
     Optimized,
 }
 
+/// The indent of the first line of a paragraph.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
+pub struct FirstLineIndent {
+    /// The width of the indent.
+    pub amount: Length,
+    /// Whether to indent all paragraphs instead of only consecutive ones.
+    pub all: bool,
+}
+
+cast! {
+    FirstLineIndent,
+    self => dict! { "amount" => self.amount, "all" => self.all }.into_value(),
+    amount: Length => Self { amount, all: false },
+    mut dict: Dict => {
+        let amount = dict.take("amount")?.cast()?;
+        let all = dict.take("all").ok().map(Value::cast).transpose()?.unwrap_or(false);
+        dict.finish(&["amount", "all"])?;
+        Self { amount, all }
+    },
+}
+
 /// A paragraph break.
 ///
 /// This starts a new paragraph. Especially useful when used within code like
@@ -239,6 +297,7 @@ type Range = std::ops::Range<usize>;
 // paragraph's full text.
 const SPACING_REPLACE: char = ' '; // Space
 const OBJ_REPLACE: char = '\u{FFFC}'; // Object Replacement Character
+const THIN_NBSP: char = '\u{202F}'; // Narrow No-Break Space
 
 /// A paragraph representation in which children are already layouted and text
 /// is already preshaped.
@@ -540,14 +599,14 @@ fn collect<'a>(
     let mut spans = SpanMapper::new();
     let mut iter = children.iter().peekable();
 
-    let first_line_indent = ParElem::first_line_indent_in(*styles);
-    if !first_line_indent.is_zero()
-        && consecutive
+    let FirstLineIndent { amount, all } = ParElem::first_line_indent_in(*styles);
+    if !amount.is_zero()
+        && (all || consecutive)
         && AlignElem::alignment_in(*styles).resolve(*styles).x
             == TextElem::dir_in(*styles).start().into()
     {
         full.push(SPACING_REPLACE);
-        segments.push((Segment::Spacing(first_line_indent.into()), *styles));
+        segments.push((Segment::Spacing(amount.into()), *styles));
     }
 
     let hang = ParElem::hanging_indent_in(*styles);
@@ -565,15 +624,29 @@ fn collect<'a>(
         }
 
         let segment = if child.is::<SpaceElem>() {
-            full.push(' ');
-            Segment::Text(1)
+            // A space in front of high punctuation or inside of guillemets
+            // becomes non-breaking.
+            let spaced = spaces_punctuation(styles)
+                && (full.ends_with('«')
+                    || iter.peek().map_or(false, |child| {
+                        let child = child.to_styled().map_or(*child, |(child, _)| child);
+                        child.to::<TextElem>().map_or(false, |elem| {
+                            starts_with_spaced_punctuation(&elem.text())
+                        })
+                    }));
+            let c = if spaced { THIN_NBSP } else { ' ' };
+            full.push(c);
+            Segment::Text(c.len_utf8())
         } else if let Some(elem) = child.to::<TextElem>() {
             let prev = full.len();
+            let mut text = elem.text();
             if let Some(case) = TextElem::case_in(styles) {
-                full.push_str(&case.apply(&elem.text()));
-            } else {
-                full.push_str(&elem.text());
+                text = case.apply_in(&text, TextElem::lang_in(styles)).into();
             }
+            if spaces_punctuation(styles) {
+                text = space_punctuation(&text, full.chars().last()).into();
+            }
+            full.push_str(&text);
             Segment::Text(full.len() - prev)
         } else if let Some(elem) = child.to::<HElem>() {
             if elem.amount().is_zero() {
@@ -657,6 +730,63 @@ fn collect<'a>(
     Ok((full, segments, spans))
 }
 
+/// Whether high punctuation should be preceded by a thin non-breaking space and
+/// guillemets should be padded with one, as is customary in French typography.
+fn spaces_punctuation(styles: StyleChain) -> bool {
+    TextElem::lang_in(styles) == Lang::FRENCH
+        && TextElem::spacing_before_punctuation_in(styles)
+}
+
+/// Whether the character is high punctuation that is spaced in French.
+fn is_high_punctuation(c: char) -> bool {
+    matches!(c, ';' | ':' | '!' | '?')
+}
+
+/// Whether the text starts with a closing guillemet or with high punctuation
+/// that ends a word, as opposed to, for example, the colon in a URL.
+fn starts_with_spaced_punctuation(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some('»') => true,
+        Some(c) if is_high_punctuation(c) => chars
+            .next()
+            .map_or(true, |c| c.is_whitespace() || is_high_punctuation(c) || c == '»'),
+        _ => false,
+    }
+}
+
+/// Insert thin non-breaking spaces in front of high punctuation and inside of
+/// guillemets. Regular spaces in those places are replaced, so that the
+/// punctuation can't be separated from its word by a line break.
+fn space_punctuation(text: &str, mut prev: Option<char>) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, c) in text.char_indices() {
+        if prev == Some('«') {
+            if c == ' ' {
+                out.push(THIN_NBSP);
+                prev = Some(THIN_NBSP);
+                continue;
+            } else if !c.is_whitespace() {
+                out.push(THIN_NBSP);
+            }
+        } else if starts_with_spaced_punctuation(&text[i..]) {
+            if out.ends_with(' ') {
+                out.pop();
+                out.push(THIN_NBSP);
+            } else if prev.map_or(false, |p| {
+                p.is_alphanumeric()
+                    || matches!(p, ')' | ']' | '}')
+                    || (c == '»' && !p.is_whitespace())
+            }) {
+                out.push(THIN_NBSP);
+            }
+        }
+        out.push(c);
+        prev = Some(c);
+    }
+    out
+}
+
 /// Prepare paragraph layout by shaping the whole paragraph and layouting all
 /// contained inline-level content.
 fn prepare<'a>(
@@ -672,6 +802,7 @@ fn prepare<'a>(
     let bidi = BidiInfo::new(
         text,
         match dir {
+            _ if detects_dir(styles) => None,
             Dir::LTR => Some(BidiLevel::ltr()),
             Dir::RTL => Some(BidiLevel::rtl()),
             _ => None,
@@ -736,6 +867,15 @@ fn prepare<'a>(
     })
 }
 
+/// Whether the paragraph's base direction is detected from its first strongly
+/// directional character instead of following the language.
+fn detects_dir(styles: StyleChain) -> bool {
+    styles
+        .properties::<TextDir>(TextElem::elem(), "dir", None)
+        .next()
+        .map_or(false, |dir| dir == TextDir::Detect)
+}
+
 /// Group a range of text by BiDi level and script, shape the runs and generate
 /// items for them.
 fn shape_range<'a>(
@@ -918,6 +1058,9 @@ fn linebreak_optimized<'a>(vt: &Vt, p: &'a Preparation<'a>, width: Abs) -> Vec<L
     }];
 
     let em = TextElem::size_in(p.styles);
+    let costs = TextElem::costs_in(p.styles);
+    let hyph_cost = HYPH_COST * costs.hyphenation().get();
+    let runt_cost = RUNT_COST * costs.runt().get();
 
     for (end, mandatory, hyphen) in breakpoints(p) {
         let k = table.len();
@@ -984,12 +1127,12 @@ fn linebreak_optimized<'a>(vt: &Vt, p: &'a Preparation<'a>, width: Abs) -> Vec<L
 
             // Penalize runts.
             if k == i + 1 && eof {
-                cost += RUNT_COST;
+                cost += runt_cost;
             }
 
             // Penalize hyphens.
             if hyphen {
-                cost += HYPH_COST;
+                cost += hyph_cost;
             }
 
             // In Knuth paper, cost = (1 + 100|r|^3 + p)^2 + a,
@@ -1103,7 +1246,7 @@ struct Breakpoints<'a> {
     /// The inner iterator over the unicode line break opportunities.
     linebreaks: Peekable<LineBreakIteratorUtf8<'a, 'a>>,
     /// Iterator over syllables of the current word.
-    syllables: Option<hypher::Syllables<'a>>,
+    syllables: Option<Syllables<'a>>,
     /// The current text offset.
     offset: usize,
     /// The trimmed end of the current word.
@@ -1150,14 +1293,22 @@ impl Iterator for Breakpoints<'_> {
                 ) || self.end == self.p.bidi.text.len()
             });
 
-        // Hyphenate the next word.
+        // Hyphenate the next word, preferring manual exceptions over the
+        // language's patterns.
         if self.p.hyphenate != Some(false) {
-            if let Some(lang) = self.lang(self.offset) {
-                let word = &self.p.bidi.text[self.offset..self.end];
-                let trimmed = word.trim_end_matches(|c: char| !c.is_alphabetic());
-                if !trimmed.is_empty() {
+            let word = &self.p.bidi.text[self.offset..self.end];
+            let trimmed = word.trim_end_matches(|c: char| !c.is_alphabetic());
+            if !trimmed.is_empty() {
+                let syllables = match self.exception(self.offset, trimmed) {
+                    Some(syllables) => Some(Syllables::Exception(syllables)),
+                    None => self.lang(self.offset).map(|lang| {
+                        Syllables::Patterns(hypher::hyphenate(trimmed, lang))
+                    }),
+                };
+
+                if syllables.is_some() {
                     self.suffix = self.offset + trimmed.len();
-                    self.syllables = Some(hypher::hyphenate(trimmed, lang));
+                    self.syllables = syllables;
                     return self.next();
                 }
             }
@@ -1182,7 +1333,7 @@ impl Iterator for Breakpoints<'_> {
     }
 }
 
-impl Breakpoints<'_> {
+impl<'a> Breakpoints<'a> {
     /// Whether hyphenation is enabled at the given offset.
     fn hyphenate(&self, offset: usize) -> bool {
         self.p
@@ -1204,6 +1355,51 @@ impl Breakpoints<'_> {
         let bytes = lang.as_str().as_bytes().try_into().ok()?;
         hypher::Lang::from_iso(bytes)
     }
+
+    /// The syllables of the word at the given offset if there is a manual
+    /// hyphenation exception for it.
+    fn exception(
+        &self,
+        offset: usize,
+        word: &'a str,
+    ) -> Option<std::vec::IntoIter<&'a str>> {
+        let shaped = self.p.find(offset)?.text()?;
+        let exceptions = TextElem::hyphenation_exceptions_in(shaped.styles);
+        let hyphenated = exceptions.get(word)?;
+
+        let mut syllables = vec![];
+        let mut rest = word;
+        for part in hyphenated.split('-') {
+            let len = rest
+                .char_indices()
+                .nth(part.chars().count())
+                .map_or(rest.len(), |(i, _)| i);
+            let (syllable, tail) = rest.split_at(len);
+            syllables.push(syllable);
+            rest = tail;
+        }
+
+        Some(syllables.into_iter())
+    }
+}
+
+/// The syllables of a word.
+enum Syllables<'a> {
+    /// Syllables determined by the language's hyphenation patterns.
+    Patterns(hypher::Syllables<'a>),
+    /// Syllables given by a manual hyphenation exception.
+    Exception(std::vec::IntoIter<&'a str>),
+}
+
+impl<'a> Iterator for Syllables<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Patterns(syllables) => syllables.next(),
+            Self::Exception(syllables) => syllables.next(),
+        }
+    }
 }
 
 /// Create a line which spans the given range.
@@ -1355,7 +1551,7 @@ fn finalize(
     lines: &[Line],
     region: Size,
     expand: bool,
-) -> SourceResult<Fragment> {
+) -> SourceResult<(Fragment, Vec<Abs>)> {
     // Determine the paragraph's width: Full width of the region if we
     // should expand or there's fractional spacing, fit-to-width otherwise.
     let width = if !region.x.is_finite()
@@ -1374,23 +1570,48 @@ fn finalize(
         .map(|line| commit(vt, p, line, width, region.y))
         .collect::<SourceResult<_>>()?;
 
+    // Determine the leading below each line. Negative leading pulls the
+    // lines closer together, but never moves a line above the top of the line
+    // before it.
+    let above = &lines[..lines.len().saturating_sub(1)];
+    let mut leadings: Vec<Abs> = above
+        .iter()
+        .zip(&frames)
+        .map(|(line, frame)| leading(p, line).max(-frame.height()))
+        .collect();
+
     // Prevent orphans.
-    let leading = ParElem::leading_in(p.styles);
-    if frames.len() >= 2 && !frames[1].is_empty() {
+    let costs = TextElem::costs_in(p.styles);
+    if costs.orphan() && frames.len() >= 2 && !frames[1].is_empty() {
         let second = frames.remove(1);
         let first = &mut frames[0];
-        merge(first, second, leading);
+        merge(first, second, leadings.remove(0));
     }
 
     // Prevent widows.
     let len = frames.len();
-    if len >= 2 && !frames[len - 2].is_empty() {
+    if costs.widow() && len >= 2 && !frames[len - 2].is_empty() {
         let second = frames.pop().unwrap();
         let first = frames.last_mut().unwrap();
-        merge(first, second, leading);
+        merge(first, second, leadings.pop().unwrap());
     }
 
-    Ok(Fragment::frames(frames))
+    Ok((Fragment::frames(frames), leadings))
+}
+
+/// The leading below a line.
+///
+/// Font-relative leading is resolved relative to the largest text in the line,
+/// but never relative to less than the paragraph's font size.
+fn leading(p: &Preparation, line: &Line) -> Abs {
+    let size = TextElem::size_in(p.styles);
+    match line.items().filter_map(Item::text).map(|shaped| shaped.size).max() {
+        Some(largest) if largest > size => {
+            let local = Styles::from(TextElem::set_size(TextSize(largest.into())));
+            ParElem::leading_in(p.styles.chain(&local))
+        }
+        _ => ParElem::leading_in(p.styles),
+    }
 }
 
 /// Merge two line frames
@@ -1456,6 +1677,38 @@ fn commit(
 
     let shrink = line.shrinkability();
     let stretch = line.stretchability();
+
+    // A line that doesn't fit even when fully shrunk contains a word that is
+    // wider than the region and that couldn't be hyphenated. It will stick
+    // out, so we let the user know, unless the paragraph is only measured.
+    // Lines with inline boxes or equations are left alone as those are
+    // typically sized on purpose.
+    if !(remaining + shrink).fits(Abs::zero())
+        && !ParElem::measuring_in(p.styles)
+        && !line.items().any(|item| matches!(item, Item::Frame(_)))
+    {
+        let text = &p.bidi.text[line.range.clone()];
+        let word = text.trim();
+        let start = line.range.start + (text.len() - text.trim_start().len());
+
+        // The span mapper attributes an offset on a segment boundary to the
+        // preceding segment, so we look one byte into the word.
+        let (span, _) = p.spans.span_at(start + 1);
+        let mut warning =
+            warning!(span, "\"{}\" is wider than the available space", word);
+
+        let hyphenate = p.hyphenate.or_else(|| {
+            let shaped = line.items().find_map(Item::text)?;
+            Some(TextElem::hyphenate_in(shaped.styles))
+        });
+
+        if hyphenate == Some(false) {
+            warning.hint("enabling hyphenation may help");
+        }
+
+        vt.tracer.warn(warning);
+    }
+
     if remaining < Abs::zero() && shrink > Abs::zero() {
         // Attempt to reduce the length of the line, using shrinkability.
         justification_ratio = (remaining / shrink).max(-1.0);
@@ -1588,6 +1841,9 @@ fn overhang(c: char) -> f64 {
         '.' | ',' => 0.8,
         ':' | ';' => 0.3,
 
+        // Quotes.
+        '”' | '’' | '»' | '›' => 0.5,
+
         // Arabic
         '\u{60C}' | '\u{6D4}' => 0.4,
 
@@ -37,7 +37,9 @@ pub struct PlaceElem {
     /// Whether the placed element has floating layout.
     ///
     /// Floating elements are positioned at the top or bottom of the page,
-    /// displacing in-flow content.
+    /// displacing in-flow content. Multiple floats at the same end of the page
+    /// stack in document order, separated by their clearance. Floats that
+    /// don't fit anymore move on to the next page.
     ///
     /// ```example
     /// #set page(height: 150pt)
@@ -62,7 +64,19 @@ pub struct PlaceElem {
     #[resolve]
     pub clearance: Length,
 
-    /// The horizontal displacement of the placed content.
+    /// The displacement of the placed content as a [coordinate]($coord).
+    ///
+    /// ```example
+    /// #set page(height: 60pt)
+    /// #let corner = coord(20pt, 10pt)
+    /// #place(offset: corner)[A]
+    /// #place(offset: corner + coord(30pt, 20pt))[B]
+    /// ```
+    #[external]
+    pub offset: Coord,
+
+    /// The horizontal displacement of the placed content. Takes precedence
+    /// over `offset`.
     ///
     /// ```example
     /// #set page(height: 100pt)
@@ -71,9 +85,15 @@ pub struct PlaceElem {
     ///   place(center, dx: amount - 32pt, dy: amount)[A]
     /// }
     /// ```
+    #[parse(
+        let offset: Option<Coord> = args.named("offset")?;
+        args.named("dx")?.or(offset.map(|offset| offset.x))
+    )]
     pub dx: Rel<Length>,
 
-    /// The vertical displacement of the placed content.
+    /// The vertical displacement of the placed content. Takes precedence over
+    /// `offset`.
+    #[parse(args.named("dy")?.or(offset.map(|offset| offset.y)))]
     pub dy: Rel<Length>,
 
     /// The content to place.
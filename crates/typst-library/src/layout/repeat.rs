@@ -6,8 +6,9 @@ use super::AlignElem;
 ///
 /// This can be useful when implementing a custom index, reference, or outline.
 ///
-/// Space may be inserted between the instances of the body parameter, so be
-/// sure to include negative space if you need the instances to overlap.
+/// Space may be inserted between the instances of the body parameter to
+/// completely fill the available space, unless `justify` is disabled. Be sure
+/// to include negative space if you need the instances to overlap.
 ///
 /// Errors if there no bounds on the available space, as it would create
 /// infinite content.
@@ -28,6 +29,23 @@ pub struct RepeatElem {
     /// The content to repeat.
     #[required]
     pub body: Content,
+
+    /// The minimum gap between the instances of the body.
+    ///
+    /// ```example
+    /// Chapter 1 #box(width: 1fr, repeat(gap: 3pt)[.]) 15
+    /// ```
+    #[resolve]
+    #[default]
+    pub gap: Length,
+
+    /// Whether to widen the gaps so that the instances exactly fill the
+    /// available space.
+    ///
+    /// When disabled, the instances are spaced by exactly `gap` and aligned
+    /// within the available space according to the current alignment.
+    #[default(true)]
+    pub justify: bool,
 }
 
 impl Layout for RepeatElem {
@@ -44,9 +62,17 @@ impl Layout for RepeatElem {
 
         let fill = regions.size.x;
         let width = piece.width();
-        let count = (fill / width).floor();
-        let remaining = fill % width;
-        let apart = remaining / (count - 1.0);
+        let mut gap = self.gap(styles);
+
+        // The instances and the gaps between them must fit into the fill, so
+        // `count * width + (count - 1) * gap <= fill`.
+        let count = ((fill + gap) / (width + gap)).floor();
+        let remaining = (fill + gap) % (width + gap);
+
+        let justify = self.justify(styles);
+        if justify && count > 1.0 {
+            gap += remaining / (count - 1.0);
+        }
 
         let size = Size::new(regions.size.x, piece.height());
 
@@ -60,14 +86,14 @@ impl Layout for RepeatElem {
         }
 
         let mut offset = Abs::zero();
-        if count == 1.0 {
+        if count == 1.0 || !justify {
             offset += align.x.position(remaining);
         }
 
         if width > Abs::zero() {
             for _ in 0..(count as usize).min(1000) {
                 frame.push_frame(Point::with_x(offset), piece.clone());
-                offset += piece.width() + apart;
+                offset += width + gap;
             }
         }
 
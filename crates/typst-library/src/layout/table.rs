@@ -1,5 +1,6 @@
 use typst::eval::{CastInfo, Reflect};
 
+use crate::layout::grid::{draw_grid_lines, fill_gutters, is_grid_line};
 use crate::layout::{AlignElem, GridLayouter, TrackSizings};
 use crate::meta::{Figurable, LocalName};
 use crate::prelude::*;
@@ -62,11 +63,17 @@ pub struct TableElem {
     #[parse(args.named("row-gutter")?.or_else(|| gutter.clone()))]
     pub row_gutter: TrackSizings,
 
+    /// How to fill the gaps between rows and columns. See the
+    /// [grid documentation]($grid.gutter-fill) for more details.
+    pub gutter_fill: Option<Paint>,
+
     /// How to fill the cells.
     ///
-    /// This can be a color or a function that returns a color. The function is
-    /// passed the cells' column and row index, starting at zero. This can be
-    /// used to implement striped tables.
+    /// This can be a color, an array of colors or a function that returns a
+    /// color. An array is cycled through the columns, so a shorter array
+    /// repeats from its start. The function is passed the cells' column and
+    /// row index, starting at zero. This can be used to implement striped
+    /// tables.
     ///
     /// ```example
     /// #table(
@@ -87,10 +94,14 @@ pub struct TableElem {
     /// How to align the cells' content.
     ///
     /// This can either be a single alignment, an array of alignments
-    /// (corresponding to each column) or a function that returns an alignment.
+    /// (corresponding to each column and cycled if there are more columns than
+    /// alignments) or a function that returns an alignment.
     /// The function is passed the cells' column and row index, starting at zero.
     /// If set to `{auto}`, the outer alignment is used.
     ///
+    /// Vertical alignments position the content within the full height of
+    /// its row, which is determined by the row's tallest cell.
+    ///
     /// ```example
     /// #table(
     ///   columns: 3,
@@ -105,6 +116,11 @@ pub struct TableElem {
     ///
     /// Strokes can be disabled by setting this to `{none}`.
     ///
+    /// Adjacent cells share their borders: A single line is drawn centered on
+    /// the edge between two cells, so the border between them is as thick as
+    /// the stroke and not twice as thick. The cells' [inset]($table.inset) is
+    /// measured from that edge.
+    ///
     /// _Note:_ Richer stroke customization for individual cells is not yet
     /// implemented, but will be in the future. In the meantime, you can use the
     /// third-party [tablex library](https://github.com/PgBiel/typst-tablex/).
@@ -113,9 +129,46 @@ pub struct TableElem {
     #[default(Some(Stroke::default()))]
     pub stroke: Option<Stroke>,
 
+    /// How much to round the table's outer corners. See the
+    /// [rectangle's documentation]($rect.radius) for more details.
+    ///
+    /// Only the outer border is rounded, the lines between the cells stay
+    /// straight. The fills of the corner cells are rounded along with the
+    /// border.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   radius: 4pt,
+    ///   fill: (_, row) => if row == 0 { luma(230) },
+    ///   [*Name*], [*Age*],
+    ///   [Alice], [30],
+    ///   [Bob], [25],
+    /// )
+    /// ```
+    #[resolve]
+    #[fold]
+    pub radius: Corners<Option<Rel<Length>>>,
+
     /// How much to pad the cells' content.
-    #[default(Abs::pt(5.0).into())]
-    pub inset: Rel<Length>,
+    ///
+    /// This can be a single padding for all sides, a dictionary of paddings
+    /// per side (see the [box's documentation]($box.inset)), an array of
+    /// paddings corresponding to each column or a function that returns a
+    /// padding. The function is passed the cells' column and row index,
+    /// starting at zero.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 2,
+    ///   inset: (_, row) => if row == 0 { 10pt } else { 5pt },
+    ///   [*Name*], [*Age*],
+    ///   [Alice], [30],
+    ///   [Bob], [25],
+    /// )
+    /// ```
+    #[default(Celled::Value(Sides::splat(Some(Abs::pt(5.0).into()))))]
+    pub inset: Celled<Sides<Option<Rel<Length>>>>,
 
     /// The contents of the table cells.
     #[variadic]
@@ -136,15 +189,17 @@ impl Layout for TableElem {
         let tracks = Axes::new(self.columns(styles).0, self.rows(styles).0);
         let gutter = Axes::new(self.column_gutter(styles).0, self.row_gutter(styles).0);
         let cols = tracks.x.len().max(1);
-        let cells: Vec<_> = self
-            .children()
+        let (lines, cells): (Vec<_>, Vec<_>) =
+            self.children().into_iter().partition(is_grid_line);
+        let cells: Vec<_> = cells
             .into_iter()
             .enumerate()
             .map(|(i, child)| {
-                let mut child = child.padded(Sides::splat(inset));
-
                 let x = i % cols;
                 let y = i / cols;
+                let inset = inset.resolve(vt, x, y)?;
+                let mut child = child.padded(inset.map(Option::unwrap_or_default));
+
                 if let Smart::Custom(alignment) = align.resolve(vt, x, y)? {
                     child = child.styled(AlignElem::set_alignment(alignment));
                 }
@@ -155,6 +210,8 @@ impl Layout for TableElem {
 
         let fill = self.fill(styles);
         let stroke = self.stroke(styles).map(Stroke::unwrap_or_default);
+        let radius = self.radius(styles);
+        let rounded = radius.iter().any(|corner| !corner.is_zero());
 
         // Prepare grid layout by unifying content and gutter tracks.
         let layouter = GridLayouter::new(
@@ -174,13 +231,28 @@ impl Layout for TableElem {
                 continue;
             }
 
+            // Resolve the corner radii against this frame, which is one region
+            // of the table.
+            let min = frame.width().min(frame.height());
+            let radius = radius.map(|corner| corner.relative_to(min).min(min / 2.0));
+
             // Render table lines.
             if let Some(stroke) = &stroke {
                 let thickness = stroke.thickness;
                 let half = thickness / 2.0;
 
+                // When the corners are rounded, the outer lines are replaced
+                // by a rounded border.
+                let inner = |offsets: Vec<Abs>| {
+                    let len = offsets.len();
+                    let skip = if rounded { 1 } else { 0 };
+                    offsets.into_iter().skip(skip).take(len.saturating_sub(2 * skip))
+                };
+
                 // Render horizontal lines.
-                for offset in points(rows.iter().map(|piece| piece.height)) {
+                for offset in
+                    inner(points(rows.iter().map(|piece| piece.height)).collect())
+                {
                     let target = Point::with_x(frame.width() + thickness);
                     let hline = Geometry::Line(target).stroked(stroke.clone());
                     frame.prepend(
@@ -190,7 +262,7 @@ impl Layout for TableElem {
                 }
 
                 // Render vertical lines.
-                for offset in points(layout.cols.iter().copied()) {
+                for offset in inner(points(layout.cols.iter().copied()).collect()) {
                     let target = Point::with_y(frame.height() + thickness);
                     let vline = Geometry::Line(target).stroked(stroke.clone());
                     frame.prepend(
@@ -198,18 +270,41 @@ impl Layout for TableElem {
                         FrameItem::Shape(vline, self.span()),
                     );
                 }
+
+                // Render the rounded outer border.
+                if rounded {
+                    let strokes = Sides::splat(Some(stroke.clone()));
+                    frame.prepend_multiple(
+                        styled_rect(frame.size(), radius.map(Into::into), None, strokes)
+                            .into_iter()
+                            .map(|shape| {
+                                (Point::zero(), FrameItem::Shape(shape, self.span()))
+                            }),
+                    );
+                }
             }
 
             // Render cell backgrounds.
             let mut dx = Abs::zero();
             for (x, &col) in layout.cols.iter().enumerate() {
                 let mut dy = Abs::zero();
-                for row in rows {
+                for (i, row) in rows.iter().enumerate() {
                     if let Some(fill) = fill.resolve(vt, x, row.y)? {
                         let pos = Point::new(dx, dy);
                         let size = Size::new(col, row.height);
-                        let rect = Geometry::Rect(size).filled(fill);
-                        frame.prepend(pos, FrameItem::Shape(rect, self.span()));
+                        let corners =
+                            cell_radius(radius, x, i, layout.cols.len(), rows.len());
+                        let shapes = if corners.iter().all(|corner| corner.is_zero()) {
+                            vec![Geometry::Rect(size).filled(fill)]
+                        } else {
+                            let corners = corners.map(Into::into);
+                            styled_rect(size, corners, Some(fill), Sides::splat(None))
+                        };
+                        frame.prepend_multiple(
+                            shapes
+                                .into_iter()
+                                .map(|shape| (pos, FrameItem::Shape(shape, self.span()))),
+                        );
                     }
                     dy += row.height;
                 }
@@ -217,6 +312,8 @@ impl Layout for TableElem {
             }
         }
 
+        fill_gutters(&mut layout, self.gutter_fill(styles), self.span());
+        draw_grid_lines(&mut layout, &lines, styles);
         Ok(layout.fragment)
     }
 }
@@ -231,6 +328,27 @@ fn points(extents: impl IntoIterator<Item = Abs>) -> impl Iterator<Item = Abs> {
     })
 }
 
+/// The corner radii of the cell in column `x` and row `y` of a table with
+/// `cols` columns and `rows` rows. Only the corners that coincide with the
+/// table's outer corners are rounded.
+fn cell_radius(
+    radius: Corners<Abs>,
+    x: usize,
+    y: usize,
+    cols: usize,
+    rows: usize,
+) -> Corners<Abs> {
+    let (left, right) = (x == 0, x + 1 == cols);
+    let (top, bottom) = (y == 0, y + 1 == rows);
+    let pick = |outer: bool, radius: Abs| if outer { radius } else { Abs::zero() };
+    Corners {
+        top_left: pick(top && left, radius.top_left),
+        top_right: pick(top && right, radius.top_right),
+        bottom_right: pick(bottom && right, radius.bottom_right),
+        bottom_left: pick(bottom && left, radius.bottom_left),
+    }
+}
+
 /// A value that can be configured per cell.
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum Celled<T> {
@@ -238,7 +356,8 @@ pub enum Celled<T> {
     Value(T),
     /// A closure mapping from cell coordinates to a value.
     Func(Func),
-    /// An array of alignment values corresponding to each column.
+    /// An array of values corresponding to each column. If there are more
+    /// columns than values, the array is cycled.
     Array(Vec<T>),
 }
 
@@ -334,3 +453,36 @@ impl LocalName for TableElem {
 }
 
 impl Figurable for TableElem {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_outer_cell_corners_are_rounded() {
+        let radius = Corners::splat(Abs::pt(4.0));
+        let zero = Abs::zero();
+        let round = Abs::pt(4.0);
+
+        let top_left = cell_radius(radius, 0, 0, 3, 2);
+        assert_eq!(top_left, Corners { top_left: round, ..Corners::splat(zero) });
+
+        let bottom_right = cell_radius(radius, 2, 1, 3, 2);
+        assert_eq!(bottom_right, Corners { bottom_right: round, ..Corners::splat(zero) });
+
+        // Cells on the edge but not in a corner stay square.
+        assert_eq!(cell_radius(radius, 1, 0, 3, 2), Corners::splat(zero));
+        assert_eq!(cell_radius(radius, 1, 1, 3, 2), Corners::splat(zero));
+
+        // A single cell takes all corners.
+        assert_eq!(cell_radius(radius, 0, 0, 1, 1), radius);
+    }
+
+    #[test]
+    fn test_adjacent_cells_share_one_line() {
+        // Two columns have three lines: one on each outer edge and a single
+        // one between the cells.
+        let offsets: Vec<_> = points([Abs::pt(10.0), Abs::pt(5.0)]).collect();
+        assert_eq!(offsets, [Abs::zero(), Abs::pt(10.0), Abs::pt(15.0)]);
+    }
+}
@@ -94,6 +94,22 @@ pub struct RotateElem {
     #[default(HAlign::Center + VAlign::Horizon)]
     pub origin: Align,
 
+    /// Whether the rotation affects the layout.
+    ///
+    /// If set to `{true}`, the rotated content takes up the space of its
+    /// bounding box, so that surrounding content and containers make room for
+    /// it. The origin then has no effect.
+    ///
+    /// ```example
+    /// #table(
+    ///   columns: 3,
+    ///   rotate(-90deg, reflow: true)[*Year*],
+    ///   [2022], [2023],
+    /// )
+    /// ```
+    #[default(false)]
+    pub reflow: bool,
+
     /// The content to rotate.
     #[required]
     pub body: Content,
@@ -109,6 +125,24 @@ impl Layout for RotateElem {
     ) -> SourceResult<Fragment> {
         let pod = Regions::one(regions.base(), Axes::splat(false));
         let mut frame = self.body().layout(vt, styles, pod)?.into_frame();
+        if self.reflow(styles) {
+            // Rotate around the top-left corner and then move the bounding box
+            // of the rotated frame to the origin.
+            let ts = Transform::rotate(self.angle(styles));
+            let size = frame.size();
+            let corners = [Point::zero(), Point::with_x(size.x), Point::with_y(size.y)]
+                .map(|corner| corner.transform(ts));
+            let (min, max) = corners.into_iter().fold(
+                (size.to_point().transform(ts), size.to_point().transform(ts)),
+                |(min, max), corner| (min.min(corner), max.max(corner)),
+            );
+            frame.transform(Transform::translate(-min.x, -min.y).pre_concat(ts));
+
+            let mut output = Frame::new((max - min).to_size());
+            output.push_frame(Point::zero(), frame);
+            return Ok(Fragment::frame(output));
+        }
+
         let Axes { x, y } = self
             .origin(styles)
             .resolve(styles)
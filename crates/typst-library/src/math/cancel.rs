@@ -95,10 +95,16 @@ impl LayoutMath for CancelElem {
         let span = self.span();
         let length = self.length(styles).resolve(styles);
 
-        let stroke = self.stroke(styles).unwrap_or(FixedStroke {
-            paint: TextElem::fill_in(styles),
-            ..Default::default()
-        });
+        // Without a paint of its own, the line takes the text's, so invisible
+        // text isn't cancelled visibly.
+        let stroke = self.stroke(styles);
+        let Some(paint) =
+            stroke.paint.clone().as_custom().or_else(|| TextElem::fill_in(styles))
+        else {
+            ctx.push(FrameFragment::new(ctx, body).with_class(body_class));
+            return Ok(());
+        };
+        let stroke = stroke.unwrap_or(FixedStroke { paint, ..Default::default() });
 
         let invert = self.inverted(styles);
         let cross = self.cross(styles);
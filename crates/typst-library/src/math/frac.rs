@@ -126,17 +126,19 @@ fn layout(
         right.center_on_axis(ctx);
         ctx.push(right);
     } else {
-        frame.push(
-            line_pos,
-            FrameItem::Shape(
-                Geometry::Line(Point::with_x(line_width)).stroked(FixedStroke {
-                    paint: TextElem::fill_in(ctx.styles()),
-                    thickness,
-                    ..FixedStroke::default()
-                }),
-                span,
-            ),
-        );
+        if let Some(paint) = TextElem::fill_in(ctx.styles()) {
+            frame.push(
+                line_pos,
+                FrameItem::Shape(
+                    Geometry::Line(Point::with_x(line_width)).stroked(FixedStroke {
+                        paint,
+                        thickness,
+                        ..FixedStroke::default()
+                    }),
+                    span,
+                ),
+            );
+        }
         ctx.push(FrameFragment::new(ctx, frame));
     }
 
@@ -186,7 +186,7 @@ pub struct GlyphFragment {
     pub c: char,
     pub font: Font,
     pub lang: Lang,
-    pub fill: Paint,
+    pub fill: Option<Paint>,
     pub shift: Abs,
     pub width: Abs,
     pub ascent: Abs,
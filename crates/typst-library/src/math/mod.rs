@@ -133,6 +133,19 @@ pub fn module() -> Module {
 /// least one space lifts it into a separate block that is centered
 /// horizontally. For more details about math syntax, see the
 /// [main math page]($category/math).
+///
+/// # Spacing
+/// Block equations are spaced like other blocks. To set the spacing above and
+/// below them apart from paragraph spacing, use a show-set rule for
+/// [blocks]($block). Adjacent spacing collapses as for any other block.
+///
+/// ```example
+/// #show math.equation: set block(above: 1em, below: 2em)
+///
+/// We know that
+/// $ a^2 + b^2 = c^2 $
+/// for right-angled triangles.
+/// ```
 #[elem(
     Locatable, Synthesize, Show, Finalize, Layout, LayoutMath, Count, LocalName, Refable,
     Outlinable
@@ -118,17 +118,19 @@ fn layout(
     }
 
     frame.push_frame(sqrt_pos, sqrt);
-    frame.push(
-        line_pos,
-        FrameItem::Shape(
-            Geometry::Line(Point::with_x(radicand.width())).stroked(FixedStroke {
-                paint: TextElem::fill_in(ctx.styles()),
-                thickness,
-                ..FixedStroke::default()
-            }),
-            span,
-        ),
-    );
+    if let Some(paint) = TextElem::fill_in(ctx.styles()) {
+        frame.push(
+            line_pos,
+            FrameItem::Shape(
+                Geometry::Line(Point::with_x(radicand.width())).stroked(FixedStroke {
+                    paint,
+                    thickness,
+                    ..FixedStroke::default()
+                }),
+                span,
+            ),
+        );
+    }
 
     frame.push_frame(radicand_pos, radicand);
     ctx.push(FrameFragment::new(ctx, frame));
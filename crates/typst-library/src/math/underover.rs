@@ -92,17 +92,19 @@ fn layout_underoverline(
     let mut frame = Frame::new(size);
     frame.set_baseline(baseline);
     frame.push_frame(content_pos, content.into_frame());
-    frame.push(
-        line_pos,
-        FrameItem::Shape(
-            Geometry::Line(Point::with_x(width)).stroked(FixedStroke {
-                paint: TextElem::fill_in(ctx.styles()),
-                thickness: bar_height,
-                ..FixedStroke::default()
-            }),
-            span,
-        ),
-    );
+    if let Some(paint) = TextElem::fill_in(ctx.styles()) {
+        frame.push(
+            line_pos,
+            FrameItem::Shape(
+                Geometry::Line(Point::with_x(width)).stroked(FixedStroke {
+                    paint,
+                    thickness: bar_height,
+                    ..FixedStroke::default()
+                }),
+                span,
+            ),
+        );
+    }
 
     ctx.push(FrameFragment::new(ctx, frame).with_class(content_class));
 
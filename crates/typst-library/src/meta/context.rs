@@ -45,10 +45,10 @@ impl Show for LocateElem {
 
 /// Provides access to active styles.
 ///
-/// The styles are currently opaque and only useful in combination with the
-/// [`measure`]($measure) function. See its documentation for more details. In
-/// the future, the provided styles might also be directly accessed to look up
-/// styles defined by [set rules]($styling/#set-rules).
+/// The styles can be passed to the [`measure`]($measure) function. See its
+/// documentation for more details. You can also look up the active value of a
+/// property defined by [set rules]($styling/#set-rules) with the styles'
+/// `get` method.
 ///
 /// ```example
 /// #let thing(body) = style(styles => {
@@ -112,6 +112,20 @@ impl Show for StyleElem {
 /// page it receives the page's dimensions minus its margins. This is mostly
 /// useful in combination with [measurement]($measure).
 ///
+/// Together with [measurement]($measure) and [scaling]($scale), this makes it
+/// possible to shrink content to the available width:
+///
+/// ```example
+/// #let fit(body) = layout(size => style(styles => {
+///   let width = measure(body, styles).width
+///   if width <= size.width { return body }
+///   let factor = size.width / width * 100%
+///   scale(x: factor, y: factor, origin: top + left, body)
+/// }))
+///
+/// #fit(rect(width: 500pt)[Too wide])
+/// ```
+///
 /// You can also use this function to resolve [`ratio`]($ratio) to fixed
 /// lengths. This might come in handy if you're building your own layout
 /// abstractions.
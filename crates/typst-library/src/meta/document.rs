@@ -1,3 +1,6 @@
+use typst::eval::Datetime;
+
+use crate::compute::Readable;
 use crate::layout::{LayoutRoot, PageElem};
 use crate::meta::ManualPageCounter;
 use crate::prelude::*;
@@ -28,8 +31,57 @@ pub struct DocumentElem {
     pub author: Author,
 
     /// The document's keywords.
+    ///
+    /// In PDF export, they are written both to the document information
+    /// dictionary and to the XMP metadata.
     pub keywords: Keywords,
 
+    /// The document's creation date.
+    ///
+    /// If this is `{auto}`, the current date is used. Setting it to `{none}`
+    /// omits the date from the PDF metadata, so that compiling the same
+    /// document twice produces identical files.
+    ///
+    /// ```example
+    /// #set document(date: datetime(year: 2023, month: 8, day: 15))
+    /// ```
+    pub date: Smart<Option<Datetime>>,
+
+    /// Files to embed into the PDF as attachments.
+    ///
+    /// Takes a dictionary mapping file names to their contents. This is
+    /// useful for shipping the data or sources a document was generated from
+    /// along with it. PDF viewers typically list attachments in a sidebar.
+    ///
+    /// ```example
+    /// #set document(attachments: (
+    ///   "data.csv": "name,value\nrhino,42",
+    /// ))
+    ///
+    /// The data is attached to the PDF.
+    /// ```
+    pub attachments: Attachments,
+
+    /// The depth from which on bookmarks in the PDF outline are shown
+    /// collapsed when the document is opened.
+    ///
+    /// With the default of `{1}`, all bookmarks are collapsed. Setting this to
+    /// `{2}` expands the top-level bookmarks so that their direct children are
+    /// visible, while all deeper levels stay collapsed. If `{none}`, all
+    /// bookmarks are expanded. The depth refers to the nesting in the PDF
+    /// outline, which may differ from the heading level if some headings are
+    /// not [bookmarked]($heading.bookmarked).
+    ///
+    /// ```example
+    /// #set document(outline-collapse: 2)
+    ///
+    /// = Introduction
+    /// == Motivation
+    /// === Details
+    /// ```
+    #[default(Some(NonZeroUsize::ONE))]
+    pub outline_collapse: Option<NonZeroUsize>,
+
     /// The page runs.
     #[internal]
     #[variadic]
@@ -81,6 +133,9 @@ impl LayoutRoot for DocumentElem {
             title: self.title(styles),
             author: self.author(styles).0,
             keywords: self.keywords(styles).0,
+            date: self.date(styles).unwrap_or_else(|| vt.world.today(Some(0))),
+            attachments: self.attachments(styles).0,
+            outline_collapse: self.outline_collapse(styles),
         })
     }
 }
@@ -106,3 +161,21 @@ cast! {
     v: EcoString => Self(vec![v]),
     v: Array => Self(v.into_iter().map(Value::cast).collect::<StrResult<_>>()?),
 }
+
+/// A list of named file attachments.
+#[derive(Debug, Default, Clone, Hash)]
+pub struct Attachments(Vec<(EcoString, Bytes)>);
+
+cast! {
+    Attachments,
+    self => self.0
+        .into_iter()
+        .map(|(name, data)| (name.into(), data.into_value()))
+        .collect::<Dict>()
+        .into_value(),
+    v: Dict => Self(
+        v.into_iter()
+            .map(|(name, data)| Ok((name.into(), data.cast::<Readable>()?.into())))
+            .collect::<StrResult<_>>()?
+    ),
+}
@@ -173,6 +173,27 @@ pub struct FigureElem {
 
     /// How to number the figure. Accepts a
     /// [numbering pattern or function]($numbering).
+    ///
+    /// To number figures per chapter, reset their counter at each top-level
+    /// heading and include the heading number in a numbering function. Since
+    /// the chapter is looked up where the number is displayed, references to
+    /// a figure should be in the same chapter as the figure itself.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #show heading.where(level: 1): it => {
+    ///   counter(figure.where(kind: image)).update(0)
+    ///   it
+    /// }
+    /// #set figure(numbering: n => locate(loc => {
+    ///   let chapter = counter(heading).at(loc).first()
+    ///   numbering("1.1", chapter, n)
+    /// }))
+    ///
+    /// = Results
+    /// #figure(rect[A], caption: [First])
+    /// #figure(rect[B], caption: [Second])
+    /// ```
     #[default(Some(NumberingPattern::from_str("1").unwrap().into()))]
     pub numbering: Option<Numbering>,
 
@@ -184,6 +205,14 @@ pub struct FigureElem {
     #[default(true)]
     pub outlined: bool,
 
+    /// Whether the figure sticks to the content that follows it.
+    ///
+    /// Like a [heading]($heading.sticky), a sticky figure moves to the next
+    /// page together with the following content if that content doesn't fit
+    /// below it. This has no effect on floating figures.
+    #[default(false)]
+    pub sticky: bool,
+
     /// Convenience field to get access to the counter for this figure.
     ///
     /// The counter only depends on the `kind`:
@@ -319,9 +348,11 @@ impl Show for FigureElem {
 }
 
 impl Finalize for FigureElem {
-    fn finalize(&self, realized: Content, _: StyleChain) -> Content {
+    fn finalize(&self, realized: Content, styles: StyleChain) -> Content {
         // Allow breakable figures with `show figure: set block(breakable: true)`.
-        realized.styled(BlockElem::set_breakable(false))
+        realized
+            .styled(BlockElem::set_breakable(false))
+            .styled(BlockElem::set_sticky(self.sticky(styles)))
     }
 }
 
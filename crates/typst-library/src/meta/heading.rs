@@ -2,10 +2,10 @@ use typst::font::FontWeight;
 use typst::util::option_eq;
 
 use super::{Counter, CounterUpdate, LocalName, Numbering, Outlinable, Refable};
-use crate::layout::{BlockElem, HElem, VElem};
+use crate::layout::{BlockElem, HElem, ParElem, VElem};
 use crate::meta::{Count, Supplement};
 use crate::prelude::*;
-use crate::text::{SpaceElem, TextElem, TextSize};
+use crate::text::{LinebreakElem, SpaceElem, TextElem, TextSize};
 
 /// A section heading.
 ///
@@ -54,6 +54,19 @@ pub struct HeadingElem {
     /// == A subsection
     /// === A sub-subsection
     /// ```
+    ///
+    /// To number appendices with letters, reset the heading counter and switch
+    /// to a pattern with letters.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.1")
+    /// = Introduction
+    ///
+    /// #counter(heading).update(0)
+    /// #set heading(numbering: "A.1")
+    /// = Data
+    /// == Raw Measurements
+    /// ```
     pub numbering: Option<Numbering>,
 
     /// A supplement for the heading.
@@ -74,6 +87,31 @@ pub struct HeadingElem {
     /// ```
     pub supplement: Smart<Option<Supplement>>,
 
+    /// Whether to place the heading's supplement and number on a line of their
+    /// own above its title, as is common for the chapters of a book.
+    ///
+    /// This has no effect if the heading isn't numbered. In the
+    /// [outline]($outline), the supplement and number are put in front of the
+    /// title instead.
+    ///
+    /// ```example
+    /// #set heading(
+    ///   numbering: "1",
+    ///   supplement: [Chapter],
+    ///   prefixed: true,
+    /// )
+    ///
+    /// #outline()
+    ///
+    /// = Introduction
+    /// In recent years, ...
+    ///
+    /// = Background
+    /// To start, ...
+    /// ```
+    #[default(false)]
+    pub prefixed: bool,
+
     /// Whether the heading should appear in the [outline]($outline).
     ///
     /// Note that this property, if set to `{true}`, ensures the heading is also
@@ -115,6 +153,34 @@ pub struct HeadingElem {
     #[default(Smart::Auto)]
     pub bookmarked: Smart<bool>,
 
+    /// Whether the heading sticks to the content that follows it.
+    ///
+    /// A sticky heading is never left alone at the bottom of a page: If the
+    /// first line of the following paragraph doesn't fit below it, the heading
+    /// moves to the next page together with that paragraph.
+    ///
+    /// ```example
+    /// #set page(height: 80pt, margin: 10pt)
+    /// #v(40pt)
+    /// = Sticky
+    /// Moves along with its body.
+    /// ```
+    #[default(true)]
+    pub sticky: bool,
+
+    /// Whether the lines of a numbered heading that wrap align with the
+    /// heading's title instead of its number.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// = A heading whose title is long enough to wrap
+    ///
+    /// #set heading(hanging-indent: true)
+    /// = A heading whose title is long enough to wrap
+    /// ```
+    #[default(false)]
+    pub hanging_indent: bool,
+
     /// The heading's title.
     #[required]
     pub body: Content,
@@ -132,6 +198,7 @@ impl Synthesize for HeadingElem {
         self.push_level(self.level(styles));
         self.push_numbering(self.numbering(styles));
         self.push_supplement(Smart::Custom(Some(Supplement::Content(supplement))));
+        self.push_prefixed(self.prefixed(styles));
         self.push_outlined(self.outlined(styles));
         self.push_bookmarked(self.bookmarked(styles));
 
@@ -139,16 +206,39 @@ impl Synthesize for HeadingElem {
     }
 }
 
+impl HeadingElem {
+    /// The heading's supplement followed by its number, for prefixed headings.
+    fn prefix(&self, number: Content) -> Content {
+        let supplement = Refable::supplement(self);
+        if supplement.is_empty() {
+            return number;
+        }
+        supplement + TextElem::packed('\u{a0}') + number
+    }
+}
+
 impl Show for HeadingElem {
     #[tracing::instrument(name = "HeadingElem::show", skip_all)]
-    fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+    fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
         let mut realized = self.body();
         if let Some(numbering) = self.numbering(styles) {
-            realized = Counter::of(Self::elem())
-                .display(Some(numbering), false)
-                .spanned(self.span())
-                + HElem::new(Em::new(0.3).into()).with_weak(true).pack()
-                + realized;
+            let number = Counter::of(Self::elem())
+                .display(Some(numbering.clone()), false)
+                .spanned(self.span());
+            if self.prefixed(styles) {
+                realized = self.prefix(number) + LinebreakElem::new().pack() + realized;
+            } else {
+                let gap = Em::new(0.3);
+                realized =
+                    number + HElem::new(gap.into()).with_weak(true).pack() + realized;
+                if self.hanging_indent(styles) {
+                    let number = Counter::of(Self::elem())
+                        .at(vt, self.0.location().unwrap())?
+                        .display(vt, &numbering)?;
+                    realized =
+                        HangingHeadingElem::new(number, gap.into(), realized).pack();
+                }
+            }
         }
         Ok(BlockElem::new().with_body(Some(realized)).pack())
     }
@@ -172,11 +262,40 @@ impl Finalize for HeadingElem {
         styles.set(TextElem::set_weight(FontWeight::BOLD));
         styles.set(BlockElem::set_above(VElem::block_around(above.into())));
         styles.set(BlockElem::set_below(VElem::block_around(below.into())));
-        styles.set(BlockElem::set_sticky(true));
+        styles.set(BlockElem::set_sticky(self.sticky(styles)));
         realized.styled_with_map(styles)
     }
 }
 
+/// A numbered heading's body whose wrapped lines hang past its number.
+///
+/// The number is measured when this element is shown, so that the heading's
+/// final text styles apply to it.
+#[elem(Show)]
+struct HangingHeadingElem {
+    /// The heading's number, as displayed in front of its title.
+    #[required]
+    number: Content,
+
+    /// The gap between the number and the title.
+    #[required]
+    gap: Length,
+
+    /// The number, the gap, and the title.
+    #[required]
+    body: Content,
+}
+
+impl Show for HangingHeadingElem {
+    #[tracing::instrument(name = "HangingHeadingElem::show", skip_all)]
+    fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+        let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
+        let width = self.number().measure(vt, styles, pod)?.into_frame().width();
+        let indent = Length::from(width) + self.gap();
+        Ok(self.body().styled(ParElem::set_hanging_indent(indent)))
+    }
+}
+
 impl Count for HeadingElem {
     fn update(&self) -> Option<CounterUpdate> {
         self.numbering(StyleChain::default())
@@ -219,7 +338,12 @@ impl Outlinable for HeadingElem {
             let numbers = Counter::of(Self::elem())
                 .at(vt, self.0.location().unwrap())?
                 .display(vt, &numbering)?;
-            content = numbers + SpaceElem::new().pack() + content;
+            let prefix = if self.prefixed(StyleChain::default()) {
+                self.prefix(numbers)
+            } else {
+                numbers
+            };
+            content = prefix + SpaceElem::new().pack() + content;
         };
 
         Ok(Some(content))
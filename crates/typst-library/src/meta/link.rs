@@ -71,6 +71,16 @@ pub struct LinkElem {
         _ => args.expect("body")?,
     })]
     pub body: Content,
+
+    /// A tooltip for the link.
+    ///
+    /// In the PDF export, the tooltip is shown by viewers when hovering over
+    /// the link.
+    ///
+    /// ```example
+    /// #link("https://typst.app", tooltip: "Typst's website")[Typst]
+    /// ```
+    pub tooltip: Option<EcoString>,
 }
 
 impl LinkElem {
@@ -82,16 +92,21 @@ impl LinkElem {
 }
 
 impl Show for LinkElem {
-    #[tracing::instrument(name = "LinkElem::show", skip(self, vt))]
-    fn show(&self, vt: &mut Vt, _: StyleChain) -> SourceResult<Content> {
+    #[tracing::instrument(name = "LinkElem::show", skip_all)]
+    fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
         let body = self.body();
+        let tooltip = self.tooltip(styles);
+        let link = |body: Content, dest| {
+            body.styled(MetaElem::set_data(vec![Meta::Link(dest, tooltip.clone())]))
+        };
+
         let linked = match self.dest() {
-            LinkTarget::Dest(dest) => body.linked(dest),
+            LinkTarget::Dest(dest) => link(body, dest),
             LinkTarget::Label(label) => vt
                 .delayed(|vt| {
                     let elem = vt.introspector.query_label(&label).at(self.span())?;
                     let dest = Destination::Location(elem.location().unwrap());
-                    Ok(Some(body.clone().linked(dest)))
+                    Ok(Some(link(body.clone(), dest)))
                 })
                 .unwrap_or(body),
         };
@@ -1,5 +1,7 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
+use comemo::Prehashed;
 use typst::util::option_eq;
 
 use super::{
@@ -112,12 +114,35 @@ pub struct OutlineElem {
     /// ```
     pub depth: Option<NonZeroUsize>,
 
+    /// The minimum level from which elements are included in the outline.
+    /// Together with [`depth`]($outline.depth), this selects a range of
+    /// levels. Entries whose ancestors are excluded are indented as if they
+    /// were top-level entries.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #outline(min-depth: 2, depth: 3)
+    ///
+    /// = Nope
+    /// Not included.
+    ///
+    /// == Yes
+    /// Subsection.
+    ///
+    /// === Yes
+    /// Subsubsection.
+    /// ```
+    #[default(NonZeroUsize::ONE)]
+    pub min_depth: NonZeroUsize,
+
     /// How to indent the outline's entries.
     ///
     /// - `{none}`: No indent
     /// - `{auto}`: Indents the numbering of the nested entry with the title of
     ///   its parent entry. This only has an effect if the entries are numbered
-    ///   (e.g., via [heading numbering]($heading.numbering)).
+    ///   (e.g., via [heading numbering]($heading.numbering)). If the numbers of
+    ///   entries on the same level have different lengths, like `9.` and `10.`,
+    ///   the shorter ones are padded so that the titles align.
     /// - [Relative length]($relative): Indents the item by this length
     ///   multiplied by its nesting level. Specifying `{2em}`, for instance,
     ///   would indent top-level headings (not nested) by `{0em}`, second level
@@ -201,9 +226,16 @@ impl Show for OutlineElem {
 
         let indent = self.indent(styles);
         let depth = self.depth(styles).unwrap_or(NonZeroUsize::new(usize::MAX).unwrap());
+        let min_depth = self.min_depth(styles);
 
         let mut ancestors: Vec<&Content> = vec![];
         let elems = vt.introspector.query(&self.target(styles).0);
+        let pads = match indent {
+            Some(Smart::Auto | Smart::Custom(OutlineIndent::Bool(true))) => {
+                numbering_pads(vt, styles, &elems)?
+            }
+            _ => HashMap::new(),
+        };
 
         for elem in &elems {
             let Some(entry) = OutlineEntry::from_outlinable(
@@ -217,7 +249,7 @@ impl Show for OutlineElem {
             };
 
             let level = entry.level();
-            if depth < level {
+            if depth < level || level < min_depth {
                 continue;
             }
 
@@ -231,7 +263,15 @@ impl Show for OutlineElem {
                 ancestors.pop();
             }
 
-            OutlineIndent::apply(&indent, vt, &ancestors, &mut seq, self.span())?;
+            OutlineIndent::apply(
+                &indent,
+                vt,
+                elem,
+                &ancestors,
+                &pads,
+                &mut seq,
+                self.span(),
+            )?;
 
             // Add the overridable outline entry, followed by a line break.
             seq.push(entry.pack());
@@ -309,10 +349,13 @@ pub enum OutlineIndent {
 }
 
 impl OutlineIndent {
+    #[allow(clippy::too_many_arguments)]
     fn apply(
         indent: &Option<Smart<Self>>,
         vt: &mut Vt,
+        elem: &Content,
         ancestors: &Vec<&Content>,
+        pads: &HashMap<Location, Abs>,
         seq: &mut Vec<Content>,
         span: Span,
     ) -> SourceResult<()> {
@@ -322,18 +365,16 @@ impl OutlineIndent {
 
             // 'auto' | 'true' => use numbering alignment for indenting
             Some(Smart::Auto | Smart::Custom(OutlineIndent::Bool(true))) => {
+                let pad = |elem: &Content| {
+                    let pad = elem.location().and_then(|loc| pads.get(&loc).copied());
+                    HElem::new(Spacing::Rel(pad.unwrap_or_default().into())).pack()
+                };
+
                 // Add hidden ancestors numberings to realize the indent.
                 let mut hidden = Content::empty();
                 for ancestor in ancestors {
-                    let ancestor_outlinable = ancestor.with::<dyn Outlinable>().unwrap();
-
-                    if let Some(numbering) = ancestor_outlinable.numbering() {
-                        let numbers = ancestor_outlinable
-                            .counter()
-                            .at(vt, ancestor.location().unwrap())?
-                            .display(vt, &numbering)?;
-
-                        hidden += numbers + SpaceElem::new().pack();
+                    if let Some(numbers) = numbers(vt, ancestor)? {
+                        hidden += pad(ancestor) + numbers + SpaceElem::new().pack();
                     };
                 }
 
@@ -341,6 +382,10 @@ impl OutlineIndent {
                     seq.push(HideElem::new(hidden).pack());
                     seq.push(SpaceElem::new().pack());
                 }
+
+                if pads.contains_key(&elem.location().unwrap()) {
+                    seq.push(pad(elem));
+                }
             }
 
             // Length => indent with some fixed spacing per level
@@ -366,6 +411,56 @@ impl OutlineIndent {
     }
 }
 
+/// Displays the numbering of an outlined element, if it has one.
+fn numbers(vt: &mut Vt, elem: &Content) -> SourceResult<Option<Content>> {
+    let Some(outlinable) = elem.with::<dyn Outlinable>() else { return Ok(None) };
+    let Some(numbering) = outlinable.numbering() else { return Ok(None) };
+    let numbers = outlinable
+        .counter()
+        .at(vt, elem.location().unwrap())?
+        .display(vt, &numbering)?;
+    Ok(Some(numbers))
+}
+
+/// Determines how much to pad the numbering of the outlined elements so that
+/// the titles of all entries on one level align.
+///
+/// Only levels whose numbers have different lengths (like `9.` and `10.`) are
+/// padded. The padding brings each numbering to the width of the widest one on
+/// its level.
+fn numbering_pads(
+    vt: &mut Vt,
+    styles: StyleChain,
+    elems: &[Prehashed<Content>],
+) -> SourceResult<HashMap<Location, Abs>> {
+    let mut levels: HashMap<NonZeroUsize, Vec<(Location, Abs, usize)>> = HashMap::new();
+    for elem in elems {
+        let Some(numbers) = numbers(vt, elem)? else { continue };
+        let level = elem.with::<dyn Outlinable>().unwrap().level();
+        let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
+        let width = numbers.measure(vt, styles, pod)?.into_frame().width();
+        let len = numbers.plain_text().chars().count();
+        levels
+            .entry(level)
+            .or_default()
+            .push((elem.location().unwrap(), width, len));
+    }
+
+    let mut pads = HashMap::new();
+    for entries in levels.values() {
+        if entries.iter().all(|&(_, _, len)| len == entries[0].2) {
+            continue;
+        }
+
+        let max = entries.iter().map(|&(_, width, _)| width).fold(Abs::zero(), Abs::max);
+        for &(loc, width, _) in entries {
+            pads.insert(loc, max - width);
+        }
+    }
+
+    Ok(pads)
+}
+
 cast! {
     OutlineIndent,
     self => match self {
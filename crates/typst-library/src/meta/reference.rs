@@ -1,4 +1,11 @@
-use super::{BibliographyElem, CiteElem, Counter, Figurable, Numbering};
+use std::str::FromStr;
+
+use typst::util::option_eq;
+
+use super::{
+    BibliographyElem, CiteElem, Counter, CounterKey, Figurable, Numbering,
+    NumberingPattern,
+};
 use crate::math::EquationElem;
 use crate::meta::FootnoteElem;
 use crate::prelude::*;
@@ -18,6 +25,11 @@ use crate::text::TextElem;
 /// [`kind`]($figure.kind) and write a show rule for it. In the future, there
 /// might be a more direct way to define a custom referenceable element.
 ///
+/// The number in a reference is always formatted with the numbering that is
+/// active for the referenced element itself. A reference to a heading in an
+/// appendix numbered with `{"A.1"}` thus reads "Section A.1", even if the
+/// reference sits in a part of the document numbered with `{"1.1"}`.
+///
 /// If you just want to link to a labelled element and not get an automatic
 /// textual reference, consider using the [`link`]($link) function instead.
 ///
@@ -114,6 +126,26 @@ pub struct RefElem {
     /// ```
     pub supplement: Smart<Option<Supplement>>,
 
+    /// The kind of reference to produce.
+    ///
+    /// - `{"normal"}`: References the element by its number, e.g. "Figure 1".
+    /// - `{"page"}`: References the page the element is located on, e.g.
+    ///   "page 3". The page number is formatted with the page's numbering.
+    ///   Here, the supplement replaces the word "page" and the element doesn't
+    ///   need to be numbered.
+    ///
+    /// ```example
+    /// #figure(
+    ///   rect[A rectangle],
+    ///   caption: [A figure],
+    /// ) <rect>
+    ///
+    /// As seen in @rect, which is on
+    /// #ref(<rect>, form: "page") ...
+    /// ```
+    #[default(RefForm::Normal)]
+    pub form: RefForm,
+
     /// A synthesized citation.
     #[synthesized]
     pub citation: Option<CiteElem>,
@@ -159,6 +191,10 @@ impl Show for RefElem {
 
             let elem = elem.at(span)?;
 
+            if self.form(styles) == RefForm::Page {
+                return self.to_page_ref(vt, styles, &elem);
+            }
+
             if elem.func() == FootnoteElem::elem() {
                 return Ok(FootnoteElem::with_label(target).pack().spanned(span));
             }
@@ -232,6 +268,87 @@ impl RefElem {
 
         Ok(elem)
     }
+
+    /// Produce a reference to the page of the given element.
+    fn to_page_ref(
+        &self,
+        vt: &mut Vt,
+        styles: StyleChain,
+        elem: &Content,
+    ) -> SourceResult<Content> {
+        let location = elem.location().unwrap();
+        let numbering = vt
+            .introspector
+            .page_numbering(location)
+            .cast::<Option<Numbering>>()
+            .unwrap()
+            .unwrap_or_else(|| {
+                Numbering::Pattern(NumberingPattern::from_str("1").unwrap())
+            });
+
+        let numbers = Counter::new(CounterKey::Page)
+            .at(vt, location)?
+            .display(vt, &numbering.trimmed())?;
+
+        let supplement = match self.supplement(styles) {
+            Smart::Auto => TextElem::packed(page_name(
+                TextElem::lang_in(styles),
+                TextElem::region_in(styles),
+            )),
+            Smart::Custom(None) => Content::empty(),
+            Smart::Custom(Some(supplement)) => supplement.resolve(vt, [elem.clone()])?,
+        };
+
+        let mut content = numbers;
+        if !supplement.is_empty() {
+            content = supplement + TextElem::packed("\u{a0}") + content;
+        }
+
+        Ok(content.linked(Destination::Location(location)))
+    }
+}
+
+/// The localized word for a page, as used in page references.
+fn page_name(lang: Lang, region: Option<Region>) -> &'static str {
+    match lang {
+        Lang::ALBANIAN => "faqe",
+        Lang::ARABIC => "صفحة",
+        Lang::BOKMÅL => "side",
+        Lang::CHINESE if option_eq(region, "TW") => "頁",
+        Lang::CHINESE => "页",
+        Lang::CZECH => "strana",
+        Lang::DANISH => "side",
+        Lang::DUTCH => "pagina",
+        Lang::FILIPINO => "pahina",
+        Lang::FINNISH => "sivu",
+        Lang::FRENCH => "page",
+        Lang::GERMAN => "Seite",
+        Lang::HUNGARIAN => "oldal",
+        Lang::ITALIAN => "pagina",
+        Lang::NYNORSK => "side",
+        Lang::POLISH => "strona",
+        Lang::PORTUGUESE => "página",
+        Lang::ROMANIAN => "pagina",
+        Lang::RUSSIAN => "страница",
+        Lang::SLOVENIAN => "stran",
+        Lang::SPANISH => "página",
+        Lang::SWEDISH => "sida",
+        Lang::TURKISH => "sayfa",
+        Lang::UKRAINIAN => "сторінка",
+        Lang::VIETNAMESE => "trang",
+        Lang::JAPANESE => "ページ",
+        Lang::ENGLISH | _ => "page",
+    }
+}
+
+/// The kind of reference to produce.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RefForm {
+    /// A reference by the element's number.
+    #[default]
+    Normal,
+    /// A reference to the element's page.
+    Page,
 }
 
 /// Additional content for a reference.
@@ -47,7 +47,7 @@ impl ContentExt for Content {
     }
 
     fn linked(self, dest: Destination) -> Self {
-        self.styled(MetaElem::set_data(vec![Meta::Link(dest)]))
+        self.styled(MetaElem::set_data(vec![Meta::Link(dest, None)]))
     }
 
     fn backlinked(self, loc: Location) -> Self {
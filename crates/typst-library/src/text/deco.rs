@@ -392,9 +392,16 @@ pub(super) fn decorate(
         _ => return,
     };
 
+    // Lines of invisible text are invisible too, unless they have their own
+    // paint.
+    let Some(paint) = stroke.paint.clone().as_custom().or_else(|| text.fill.clone())
+    else {
+        return;
+    };
+
     let offset = offset.unwrap_or(-metrics.position.at(text.size)) - shift;
     let stroke = stroke.clone().unwrap_or(FixedStroke {
-        paint: text.fill.clone(),
+        paint,
         thickness: metrics.thickness.at(text.size),
         ..FixedStroke::default()
     });
@@ -558,3 +565,47 @@ impl OutlineBuilder for BezPathBuilder {
         self.path.close_path();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use typst::font::Font;
+
+    use super::*;
+
+    #[test]
+    fn test_underline_takes_text_fill() {
+        let data = include_bytes!("../../../../assets/fonts/IBMPlexSerif-Regular.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let text = TextItem {
+            font,
+            size: Abs::pt(10.0),
+            fill: Some(Color::RED.into()),
+            lang: Lang::ENGLISH,
+            text: "underlined".into(),
+            glyphs: vec![],
+        };
+
+        let deco = Decoration {
+            line: DecoLine::Underline {
+                stroke: Stroke::default(),
+                offset: Smart::Auto,
+                evade: false,
+                background: false,
+            },
+            extent: Abs::zero(),
+        };
+
+        let mut frame = Frame::new(Size::new(Abs::pt(50.0), Abs::pt(10.0)));
+        decorate(&mut frame, &deco, &text, Abs::pt(50.0), Abs::zero(), Point::zero());
+
+        let strokes: Vec<_> = frame
+            .items()
+            .filter_map(|(_, item)| match item {
+                FrameItem::Shape(shape, _) => shape.stroke.as_ref(),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(strokes.len(), 1);
+        assert_eq!(strokes[0].paint, Color::RED.into());
+    }
+}
@@ -250,6 +250,21 @@ impl Case {
             Self::Upper => text.to_uppercase(),
         }
     }
+
+    /// Apply the case to a string in the given language.
+    ///
+    /// This differs from [`apply`](Self::apply) for Turkish and Azerbaijani,
+    /// which distinguish between a dotted and a dotless i.
+    pub fn apply_in(self, text: &str, lang: Lang) -> String {
+        if !matches!(lang.as_str(), "tr" | "az") {
+            return self.apply(text);
+        }
+
+        match self {
+            Self::Lower => text.replace('İ', "i").replace('I', "ı").to_lowercase(),
+            Self::Upper => text.replace('i', "İ").to_uppercase(),
+        }
+    }
 }
 
 /// Displays text in small capitals.
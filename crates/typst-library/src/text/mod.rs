@@ -14,6 +14,8 @@ pub use self::raw::*;
 pub use self::shaping::*;
 pub use self::shift::*;
 
+use std::collections::BTreeMap;
+
 use rustybuzz::Tag;
 use ttf_parser::Rect;
 use typst::font::{Font, FontStretch, FontStyle, FontWeight, VerticalFontMetric};
@@ -158,7 +160,9 @@ pub struct TextElem {
     /// `{1em}` is equivalent to the font size.
     ///
     /// You can also give the font size itself in `em` units. Then, it is
-    /// relative to the previous font size.
+    /// relative to the previous font size. Nested relative sizes compound, so
+    /// two nested `{set text(size: 1.5em)}` rules result in 2.25 times the
+    /// outer size.
     ///
     /// ```example
     /// #set text(size: 20pt)
@@ -171,13 +175,24 @@ pub struct TextElem {
 
     /// The glyph fill color.
     ///
+    /// If this is `{none}`, the text is laid out as usual, but not drawn. In
+    /// PDF export, it is still selectable and can be extracted, which is
+    /// useful for things like OCR layers.
+    ///
+    /// Decorations such as underlines, list markers, and shapes that don't
+    /// specify their own stroke take on the text's fill color.
+    ///
     /// ```example
     /// #set text(fill: red)
     /// This text is red.
+    /// #text(fill: none)[Invisible.]
     /// ```
-    #[parse(args.named_or_find("fill")?)]
-    #[default(Color::BLACK.into())]
-    pub fill: Paint,
+    #[parse(match args.named("fill")? {
+        Some(fill) => Some(fill),
+        None => args.find::<Paint>()?.map(Some),
+    })]
+    #[default(Some(Color::BLACK.into()))]
+    pub fill: Option<Paint>,
 
     /// The amount of space that should be added between characters.
     ///
@@ -214,7 +229,9 @@ pub struct TextElem {
     pub baseline: Length,
 
     /// Whether certain glyphs can hang over into the margin in justified text.
-    /// This can make justification visually more pleasing.
+    /// This can make justification visually more pleasing. Justification
+    /// accounts for the hanging amount, so the glyphs' visual edge stays flush
+    /// with the margin while their geometric edge slightly exceeds it.
     ///
     /// ```example
     /// #set par(justify: true)
@@ -232,6 +249,24 @@ pub struct TextElem {
     #[default(true)]
     pub overhang: bool,
 
+    /// Whether to follow the language's conventions for spacing around
+    /// punctuation.
+    ///
+    /// Currently, this only affects French text, where a thin non-breaking
+    /// space is inserted in front of `;`, `:`, `!`, and `?` and on the inner
+    /// sides of guillemets. Regular spaces in these places are replaced, so
+    /// that the punctuation is never separated from its word by a line break.
+    ///
+    /// ```example
+    /// #set text(lang: "fr")
+    /// Bonjour! Ça va ? «Oui»
+    ///
+    /// #set text(spacing-before-punctuation: false)
+    /// Bonjour! Ça va? «Oui»
+    /// ```
+    #[default(true)]
+    pub spacing_before_punctuation: bool,
+
     /// The top end of the conceptual frame around the text used for layout and
     /// positioning. This affects the size of containers that hold text.
     ///
@@ -321,6 +356,11 @@ pub struct TextElem {
     /// The dominant direction for text and inline objects. Possible values are:
     ///
     /// - `{auto}`: Automatically infer the direction from the `lang` property.
+    /// - `{"detect"}`: Infer the base direction of each paragraph from its
+    ///   first character with a strong direction, so that a paragraph starting
+    ///   with Arabic text is laid out from right to left even if the language
+    ///   is left-to-right. Alignments like `start` and `end` still follow the
+    ///   `lang` property.
     /// - `{ltr}`: Layout text from left to right.
     /// - `{rtl}`: Layout text from right to left.
     ///
@@ -367,6 +407,49 @@ pub struct TextElem {
     #[resolve]
     pub hyphenate: Hyphenate,
 
+    /// Manual hyphenation points for specific words, which take precedence
+    /// over the language's hyphenation patterns.
+    ///
+    /// Maps each word to its hyphenated form, with hyphens marking the points
+    /// where the word may be broken. A word without any hyphens is never
+    /// hyphenated. Words are matched case-insensitively. Since the exceptions
+    /// are a dictionary, they can also be loaded from a data file, e.g. with
+    /// `{json("exceptions.json")}`. Nested rules add to the exceptions of
+    /// outer ones.
+    ///
+    /// ```example
+    /// #set page(width: 80pt)
+    /// #set par(justify: true)
+    /// #set text(hyphenation-exceptions: (
+    ///   "database": "data-base",
+    /// ))
+    ///
+    /// The database was updated.
+    /// ```
+    #[fold]
+    pub hyphenation_exceptions: HyphenationExceptions,
+
+    /// The costs of various bad breaks, relative to their defaults.
+    ///
+    /// The keys `hyphenation` and `runt` take ratios that scale the penalties
+    /// the optimized line breaker assigns to hyphenated lines and to a final
+    /// line with a single word. The keys `widow` and `orphan` take booleans
+    /// that control whether the last and the first line of a paragraph are
+    /// kept from being left alone at the top or bottom of a region. Both are
+    /// enabled by default. Omitted keys keep their current value.
+    ///
+    /// ```example
+    /// #set page(width: 200pt)
+    /// #set par(justify: true)
+    ///
+    /// #set text(costs: (hyphenation: 500%))
+    /// This text illustrates how
+    /// expensive hyphenation changes
+    /// the choice of line breaks.
+    /// ```
+    #[fold]
+    pub costs: Costs,
+
     /// Whether to apply kerning.
     ///
     /// When enabled, specific letter pairings move closer together or further
@@ -494,13 +577,25 @@ pub struct TextElem {
     ///
     /// - If given an array of strings, sets the features identified by the
     ///   strings to `{1}`.
-    /// - If given a dictionary mapping to numbers, sets the features
-    ///   identified by the keys to the values.
+    /// - If given a dictionary mapping to numbers or booleans, sets the
+    ///   features identified by the keys to the values. A boolean enables or
+    ///   disables a feature.
+    ///
+    /// Features set in nested scopes are merged: An inner setting overrides
+    /// the features it names and keeps all others active.
+    ///
+    /// Features that a script requires to render correctly, like the joining
+    /// forms and mandatory ligatures of Arabic, are applied automatically based
+    /// on the script of the text and need not be listed here.
     ///
     /// ```example
     /// // Enable the `frac` feature manually.
     /// #set text(features: ("frac",))
     /// 1/2
+    ///
+    /// // Disable ligatures, but keep fractions.
+    /// #set text(features: (liga: false))
+    /// 1/2 fi
     /// ```
     #[fold]
     pub features: FontFeatures,
@@ -771,16 +866,31 @@ impl TryInto<VerticalFontMetric> for BottomEdgeMetric {
 
 /// The direction of text and inline objects in their line.
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
-pub struct TextDir(pub Smart<Dir>);
+pub enum TextDir {
+    /// Follow the direction of the text language.
+    #[default]
+    Auto,
+    /// Detect the base direction of each paragraph from its content. Everything
+    /// else still follows the direction of the text language.
+    Detect,
+    /// A fixed direction.
+    Custom(Dir),
+}
 
 cast! {
     TextDir,
-    self => self.0.into_value(),
-    v: Smart<Dir> => {
-        if v.map_or(false, |dir| dir.axis() == Axis::Y) {
+    self => match self {
+        Self::Auto => Value::Auto,
+        Self::Detect => "detect".into_value(),
+        Self::Custom(dir) => dir.into_value(),
+    },
+    _: AutoValue => Self::Auto,
+    "detect" => Self::Detect,
+    v: Dir => {
+        if v.axis() == Axis::Y {
             bail!("text direction must be horizontal");
         }
-        Self(v)
+        Self::Custom(v)
     },
 }
 
@@ -788,9 +898,9 @@ impl Resolve for TextDir {
     type Output = Dir;
 
     fn resolve(self, styles: StyleChain) -> Self::Output {
-        match self.0 {
-            Smart::Auto => TextElem::lang_in(styles).dir(),
-            Smart::Custom(dir) => dir,
+        match self {
+            Self::Auto | Self::Detect => TextElem::lang_in(styles).dir(),
+            Self::Custom(dir) => dir,
         }
     }
 }
@@ -816,6 +926,126 @@ impl Resolve for Hyphenate {
     }
 }
 
+/// Manual hyphenation points for specific words.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct HyphenationExceptions(BTreeMap<EcoString, EcoString>);
+
+impl HyphenationExceptions {
+    /// The hyphenated form of the given word, if there is an exception for it.
+    pub fn get(&self, word: &str) -> Option<&str> {
+        if self.0.is_empty() {
+            return None;
+        }
+        self.0.get(word.to_lowercase().as_str()).map(EcoString::as_str)
+    }
+}
+
+cast! {
+    HyphenationExceptions,
+    self => self.0
+        .into_iter()
+        .map(|(word, hyphenated)| (word.into(), hyphenated.into_value()))
+        .collect::<Dict>()
+        .into_value(),
+    dict: Dict => {
+        let mut exceptions = BTreeMap::new();
+        for (word, value) in dict {
+            let hyphenated: EcoString = value.cast::<Str>()?.into();
+            if hyphenated.replace('-', "").to_lowercase() != word.to_lowercase() {
+                bail!(
+                    "hyphenated form {hyphenated:?} does not match the word {:?}",
+                    word.as_str(),
+                );
+            }
+            exceptions.insert(word.to_lowercase().into(), hyphenated);
+        }
+        Self(exceptions)
+    },
+}
+
+impl Fold for HyphenationExceptions {
+    type Output = Self;
+
+    fn fold(mut self, outer: Self::Output) -> Self::Output {
+        for (word, hyphenated) in outer.0 {
+            self.0.entry(word).or_insert(hyphenated);
+        }
+        self
+    }
+}
+
+/// The costs of bad breaks, relative to their defaults, and whether widows and
+/// orphans are prevented.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Hash)]
+pub struct Costs {
+    hyphenation: Option<Ratio>,
+    runt: Option<Ratio>,
+    widow: Option<bool>,
+    orphan: Option<bool>,
+}
+
+impl Costs {
+    /// The factor for the cost of a hyphenated line.
+    pub fn hyphenation(&self) -> Ratio {
+        self.hyphenation.unwrap_or(Ratio::one())
+    }
+
+    /// The factor for the cost of a single-word last line.
+    pub fn runt(&self) -> Ratio {
+        self.runt.unwrap_or(Ratio::one())
+    }
+
+    /// Whether a paragraph's last line is kept from starting a region.
+    pub fn widow(&self) -> bool {
+        self.widow.unwrap_or(true)
+    }
+
+    /// Whether a paragraph's first line is kept from ending a region.
+    pub fn orphan(&self) -> bool {
+        self.orphan.unwrap_or(true)
+    }
+}
+
+cast! {
+    Costs,
+    self => {
+        let mut dict = Dict::new();
+        let mut handle = |key: &str, value: Option<Value>| {
+            if let Some(value) = value {
+                dict.insert(key.into(), value);
+            }
+        };
+        handle("hyphenation", self.hyphenation.map(IntoValue::into_value));
+        handle("runt", self.runt.map(IntoValue::into_value));
+        handle("widow", self.widow.map(IntoValue::into_value));
+        handle("orphan", self.orphan.map(IntoValue::into_value));
+        dict.into_value()
+    },
+    mut dict: Dict => {
+        let costs = Self {
+            hyphenation: dict.take("hyphenation").ok().map(Value::cast).transpose()?,
+            runt: dict.take("runt").ok().map(Value::cast).transpose()?,
+            widow: dict.take("widow").ok().map(Value::cast).transpose()?,
+            orphan: dict.take("orphan").ok().map(Value::cast).transpose()?,
+        };
+        dict.finish(&["hyphenation", "runt", "widow", "orphan"])?;
+        costs
+    },
+}
+
+impl Fold for Costs {
+    type Output = Self;
+
+    fn fold(self, outer: Self::Output) -> Self::Output {
+        Self {
+            hyphenation: self.hyphenation.or(outer.hyphenation),
+            runt: self.runt.or(outer.runt),
+            widow: self.widow.or(outer.widow),
+            orphan: self.orphan.or(outer.orphan),
+        }
+    }
+}
+
 /// A stylistic set in a font.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct StylisticSet(u8);
@@ -880,24 +1110,40 @@ cast! {
         .into_iter()
         .map(|v| {
             let tag = v.cast::<EcoString>()?;
-            Ok((Tag::from_bytes_lossy(tag.as_bytes()), 1))
+            Ok((feature_tag(&tag)?, 1))
         })
         .collect::<StrResult<_>>()?),
     values: Dict => Self(values
         .into_iter()
         .map(|(k, v)| {
-            let num = v.cast::<u32>()?;
-            let tag = Tag::from_bytes_lossy(k.as_bytes());
+            let num = match v {
+                Value::Bool(enabled) => enabled.into(),
+                v => v.cast::<u32>()?,
+            };
+            let tag = feature_tag(&k)?;
             Ok((tag, num))
         })
         .collect::<StrResult<_>>()?),
 }
 
+/// Parse an OpenType feature tag, which must consist of four ASCII characters.
+fn feature_tag(tag: &str) -> StrResult<Tag> {
+    if tag.len() != 4 || !tag.is_ascii() {
+        bail!("feature tag must be four ASCII characters, found {tag:?}");
+    }
+    Ok(Tag::from_bytes_lossy(tag.as_bytes()))
+}
+
 impl Fold for FontFeatures {
     type Output = Self;
 
-    fn fold(mut self, outer: Self::Output) -> Self::Output {
-        self.0.extend(outer.0);
-        self
+    fn fold(self, mut outer: Self::Output) -> Self::Output {
+        // Later features take precedence during shaping, so the inner ones go
+        // last and replace outer ones with the same tag.
+        outer
+            .0
+            .retain(|(tag, _)| self.0.iter().all(|(other, _)| other != tag));
+        outer.0.extend(self.0);
+        outer
     }
 }
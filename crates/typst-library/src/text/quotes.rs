@@ -21,7 +21,8 @@ use crate::prelude::*;
 ///
 /// # Syntax
 /// This function also has dedicated syntax: The normal quote characters
-/// (`'` and `"`). Typst automatically makes your quotes smart.
+/// (`'` and `"`). Typst automatically makes your quotes smart. Quotes in
+/// [raw text]($raw) always stay straight.
 #[elem]
 pub struct SmartquoteElem {
     /// Whether this should be a double quote.
@@ -12,9 +12,10 @@ use typst::util::option_eq;
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::{
-    FontFamily, FontList, Hyphenate, LinebreakElem, SmartquoteElem, TextElem, TextSize,
+    shape, FontFamily, FontList, Hyphenate, LinebreakElem, SmartquoteElem, TextElem,
+    TextSize,
 };
-use crate::layout::BlockElem;
+use crate::layout::{BlockElem, BoxElem, ParElem, Sizing, SpanMapper};
 use crate::meta::{Figurable, LocalName};
 use crate::prelude::*;
 
@@ -57,7 +58,19 @@ use crate::prelude::*;
 /// needed, start the text with a single space (which will be trimmed) or use
 /// the single backtick syntax. If your text should start or end with a
 /// backtick, put a space before or after it (it will be trimmed).
+///
+/// # Ligatures
+/// Raw text is displayed without [ligatures]($text.ligatures) so that
+/// character sequences like `->` or `!=` appear exactly as they were typed.
+/// If you use a programming font with ligatures and want them to show up, you
+/// can enable them with a show-set rule:
+///
+/// ```example
+/// #show raw: set text(ligatures: true)
+/// A `fine` ligature.
+/// ```
 #[elem(
+    scope,
     title = "Raw Text / Code",
     Synthesize,
     Show,
@@ -138,6 +151,16 @@ pub struct RawElem {
     ///
     /// This is ```typ also *Typst*```, but inline!
     /// ````
+    ///
+    /// In raw blocks tagged with `{"diff"}` or `{"patch"}`, added lines get a
+    /// green background and removed lines a red one.
+    ///
+    /// ````example
+    /// ```diff
+    /// -let x = 1
+    /// +let x = 2
+    /// ```
+    /// ````
     pub lang: Option<EcoString>,
 
     /// The horizontal alignment that each line in a raw block should have.
@@ -161,6 +184,51 @@ pub struct RawElem {
     #[default(HAlign::Start)]
     pub align: HAlign,
 
+    /// How to deal with lines that are too long to fit into a raw block. This
+    /// option is ignored if this is not a raw block.
+    ///
+    /// - `{auto}`: Long lines are broken like regular text.
+    /// - `{"wrap"}`: Long lines are broken into multiple visual lines. The
+    ///   continuation lines are indented to the level of the original line
+    ///   and marked with an arrow.
+    /// - `{"scroll"}`: Long lines are kept intact and extend beyond the edge of
+    ///   the block.
+    /// - `{"truncate"}`: Long lines are cut off at the edge of the block, which
+    ///   is indicated by an ellipsis.
+    ///
+    /// ````example
+    /// #set raw(overflow: "truncate")
+    ///
+    /// ```rust
+    /// fn main() {
+    ///     println!("This line is a bit too long for the block");
+    /// }
+    /// ```
+    /// ````
+    pub overflow: Smart<RawOverflow>,
+
+    /// The background fill of inline raw text. The background hugs the text
+    /// without changing the height of its line. Inline raw text with a fill
+    /// is kept together and never broken across lines. This option is ignored
+    /// for raw blocks, whose background can be configured with a show-set rule
+    /// for [`block`]($block).
+    ///
+    /// ```example
+    /// #set raw(fill: luma(230), radius: 2pt)
+    /// Call `main()` to start.
+    /// ```
+    pub fill: Option<Paint>,
+
+    /// How much to round the corners of the background of inline raw text. See
+    /// the [rectangle's documentation]($rect.radius) for more details.
+    pub radius: Corners<Option<Rel<Length>>>,
+
+    /// How far the background of inline raw text extends around it. The
+    /// background pushes the surrounding text away horizontally, but only
+    /// overhangs the line vertically.
+    #[default(Em::new(0.2).into())]
+    pub inset: Length,
+
     /// One or multiple additional syntax definitions to load. The syntax
     /// definitions should be in the
     /// [`sublime-syntax` file format](https://www.sublimetext.com/docs/syntax.html).
@@ -192,22 +260,15 @@ pub struct RawElem {
     /// The theme to use for syntax highlighting. Theme files should be in the
     /// in the [`tmTheme` file format](https://www.sublimetext.com/docs/color_schemes_tmtheme.html).
     ///
-    /// Applying a theme only affects the color of specifically highlighted
-    /// text. It does not consider the theme's foreground and background
-    /// properties, so that you retain control over the color of raw text. You
-    /// can apply the foreground color yourself with the [`text`]($text)
-    /// function and the background with a [filled block]($block.fill). You
-    /// could also use the [`xml`]($xml) function to extract these properties
-    /// from the theme.
+    /// Besides the colors of highlighted text, a theme also provides the
+    /// default color of raw text through its foreground property. For raw
+    /// blocks, its background property is used as the [block's fill]($block.fill).
+    /// The default theme has neither, so that raw text follows the surrounding
+    /// text color.
     ///
     /// ````example
     /// #set raw(theme: "halcyon.tmTheme")
-    /// #show raw: it => block(
-    ///   fill: rgb("#1d2433"),
-    ///   inset: 8pt,
-    ///   radius: 5pt,
-    ///   text(fill: rgb("#a2aabc"), it)
-    /// )
+    /// #show raw: set block(inset: 8pt, radius: 5pt)
     ///
     /// ```typ
     /// = Chapter 1
@@ -241,6 +302,12 @@ pub struct RawElem {
     pub tab_size: usize,
 }
 
+#[scope]
+impl RawElem {
+    #[elem]
+    type RawLine;
+}
+
 impl RawElem {
     /// The supported language names and tags.
     pub fn languages() -> Vec<(&'static str, Vec<&'static str>)> {
@@ -279,6 +346,7 @@ impl Show for RawElem {
             .as_ref()
             .map(|s| s.to_lowercase())
             .or(Some("txt".into()));
+        let diff = matches!(lang.as_deref(), Some("diff" | "patch"));
 
         let extra_syntaxes = UnsyncLazy::new(|| {
             load_syntaxes(&self.syntaxes(styles), &self.syntaxes_data(styles)).unwrap()
@@ -296,24 +364,34 @@ impl Show for RawElem {
             .map(to_typst)
             .map_or(Color::BLACK, Color::from);
 
-        let mut realized = if matches!(lang.as_deref(), Some("typ" | "typst" | "typc")) {
+        let mut lines = vec![];
+        if matches!(lang.as_deref(), Some("typ" | "typst" | "typc")) {
             let root = match lang.as_deref() {
                 Some("typc") => syntax::parse_code(&text),
                 _ => syntax::parse(&text),
             };
 
-            let mut seq = vec![];
+            let mut line = vec![];
             let highlighter = synt::Highlighter::new(theme);
             highlight_themed(
                 &LinkedNode::new(&root),
                 vec![],
                 &highlighter,
                 &mut |node, style| {
-                    seq.push(styled(&text[node.range()], foreground.into(), style));
+                    // A single node may span multiple lines, so we split it up
+                    // at the line breaks.
+                    for (i, piece) in text[node.range()].split('\n').enumerate() {
+                        if i != 0 {
+                            lines.push(Content::sequence(std::mem::take(&mut line)));
+                        }
+                        if !piece.is_empty() {
+                            line.push(styled(piece, foreground.into(), style));
+                        }
+                    }
                 },
             );
 
-            Content::sequence(seq)
+            lines.push(Content::sequence(line));
         } else if let Some((syntax_set, syntax)) = lang.and_then(|token| {
             SYNTAXES
                 .find_syntax_by_token(&token)
@@ -324,29 +402,82 @@ impl Show for RawElem {
                         .map(|syntax| (&**extra_syntaxes, syntax))
                 })
         }) {
-            let mut seq = vec![];
             let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
-            for (i, line) in text.lines().enumerate() {
-                if i != 0 {
-                    seq.push(LinebreakElem::new().pack());
-                }
-
+            for line in text.split('\n') {
+                let mut seq = vec![];
                 for (style, piece) in
                     highlighter.highlight_line(line, syntax_set).into_iter().flatten()
                 {
                     seq.push(styled(piece, foreground.into(), style));
                 }
+                lines.push(Content::sequence(seq));
             }
-
-            Content::sequence(seq)
         } else {
-            TextElem::packed(text)
-        };
+            lines.extend(text.split('\n').map(TextElem::packed));
+        }
+
+        let block = self.block(styles);
+        let overflow = self.overflow(styles);
+        let count = lines.len() as i64;
+        let mut seq = vec![];
+        for (i, (line, body)) in text.split('\n').zip(lines).enumerate() {
+            if i != 0 {
+                seq.push(LinebreakElem::new().pack());
+            }
+
+            let mut realized = RawLine::new(i as i64 + 1, count, line.into(), body)
+                .pack()
+                .spanned(self.span());
+
+            // In a block, lines with a configured overflow or a background
+            // are laid out on their own.
+            if block {
+                if let Smart::Custom(overflow) = overflow {
+                    let indent = &line[..line.len() - line.trim_start().len()];
+                    realized =
+                        RawOverflowElem::new(overflow, indent.into(), realized).pack();
+                }
+
+                let fill = diff.then(|| diff_fill(line)).flatten();
+                if overflow.is_custom() || fill.is_some() {
+                    let mut boxed = BoxElem::new().with_body(Some(realized));
+                    // Changed lines in a diff are highlighted across the full
+                    // width of the block.
+                    if let Some(fill) = fill {
+                        boxed.push_width(Sizing::Fr(Fr::one()));
+                        boxed.push_fill(Some(fill));
+                    }
+                    realized = boxed.pack();
+                }
+            }
+
+            seq.push(realized);
+        }
+
+        let mut realized = Content::sequence(seq);
+
+        // The theme determines the color of unhighlighted text, if it has one.
+        if theme.settings.foreground.is_some() {
+            realized = realized.styled(TextElem::set_fill(Some(foreground.into())));
+        }
 
-        if self.block(styles) {
+        if block {
             // Align the text before inserting it into the block.
             realized = realized.aligned(self.align(styles).into());
-            realized = BlockElem::new().with_body(Some(realized)).pack();
+            let mut block = BlockElem::new().with_body(Some(realized));
+            if let Some(background) = theme.settings.background {
+                block.push_fill(Some(to_typst(background).into()));
+            }
+            realized = block.pack();
+        } else if let Some(fill) = self.fill(styles) {
+            let inset = Some(self.inset(styles).into());
+            realized = BoxElem::new()
+                .with_fill(Some(fill))
+                .with_radius(self.radius(styles))
+                .with_inset(Smart::Custom(Sides::new(inset, None, inset, None)))
+                .with_outset(Sides::new(None, inset, None, inset))
+                .with_body(Some(realized))
+                .pack();
         }
 
         Ok(realized)
@@ -357,7 +488,9 @@ impl Finalize for RawElem {
     fn finalize(&self, realized: Content, _: StyleChain) -> Content {
         let mut styles = Styles::new();
         styles.set(TextElem::set_overhang(false));
+        styles.set(TextElem::set_spacing_before_punctuation(false));
         styles.set(TextElem::set_hyphenate(Hyphenate(Smart::Custom(false))));
+        styles.set(TextElem::set_ligatures(false));
         styles.set(TextElem::set_size(TextSize(Em::new(0.8).into())));
         styles
             .set(TextElem::set_font(FontList(vec![FontFamily::new("DejaVu Sans Mono")])));
@@ -406,6 +539,155 @@ impl PlainText for RawElem {
     }
 }
 
+/// A highlighted line of raw text.
+///
+/// This is a helper element that is synthesized by [`raw`]($raw) elements.
+///
+/// It allows you to access various properties of the line, such as the line
+/// number, the raw non-highlighted text, the highlighted text, and whether it
+/// is the first or last line of the raw block.
+///
+/// ````example
+/// #show raw.line: it => {
+///   text(fill: gray)[#it.number]
+///   h(1em)
+///   it.body
+/// }
+///
+/// ```typ
+/// = Hello
+/// *World*
+/// ```
+/// ````
+#[elem(name = "line", title = "Raw Text / Code Line", Show, PlainText)]
+pub struct RawLine {
+    /// The line number of the raw line inside of the raw block, starts at 1.
+    #[required]
+    pub number: i64,
+
+    /// The total number of lines in the raw block. The line is the last one
+    /// if its `number` is equal to the `count`.
+    #[required]
+    pub count: i64,
+
+    /// The line of raw text.
+    #[required]
+    pub text: EcoString,
+
+    /// The highlighted raw text.
+    #[required]
+    pub body: Content,
+}
+
+impl Show for RawLine {
+    fn show(&self, _: &mut Vt, _: StyleChain) -> SourceResult<Content> {
+        Ok(self.body())
+    }
+}
+
+impl PlainText for RawLine {
+    fn plain_text(&self, text: &mut EcoString) {
+        text.push_str(&self.text());
+    }
+}
+
+/// How a raw block deals with lines that are too long to fit into it.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum RawOverflow {
+    /// Break long lines, indenting and marking the continuation lines.
+    #[default]
+    Wrap,
+    /// Let long lines extend beyond the block.
+    Scroll,
+    /// Cut long lines off at the edge of the block.
+    Truncate,
+}
+
+/// Lays out a single line of a raw block according to its overflow mode.
+#[elem(Layout)]
+struct RawOverflowElem {
+    /// How to deal with the line if it is too long.
+    #[required]
+    overflow: RawOverflow,
+
+    /// The leading whitespace of the line.
+    #[required]
+    indent: EcoString,
+
+    /// The line itself.
+    #[required]
+    body: Content,
+}
+
+impl Layout for RawOverflowElem {
+    #[tracing::instrument(name = "RawOverflowElem::layout", skip_all)]
+    fn layout(
+        &self,
+        vt: &mut Vt,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let unbounded =
+            Regions::one(Size::new(Abs::inf(), regions.base().y), Axes::splat(false));
+        let overflow = self.overflow();
+        if overflow == RawOverflow::Wrap {
+            // Continuation lines hang by the width of the line's indentation
+            // and the marker. The indentation is measured from the shaped
+            // spaces directly because laying it out as text would trim it
+            // away.
+            let marker =
+                TextElem::packed('↪').layout(vt, styles, unbounded)?.into_frame();
+            let indent = shape(
+                vt,
+                0,
+                self.indent(),
+                &SpanMapper::new(),
+                styles,
+                Dir::LTR,
+                TextElem::lang_in(styles),
+                TextElem::region_in(styles),
+            )
+            .width;
+            let hanging = indent + marker.width();
+            let mut frame = self
+                .body()
+                .styled(ParElem::set_hanging_indent(hanging.into()))
+                .layout(vt, styles, regions)?
+                .into_frame();
+
+            // Put the marker in front of every visual line but the first.
+            let mut baselines: Vec<Abs> = frame
+                .items()
+                .filter(|(_, item)| matches!(item, FrameItem::Text(_)))
+                .map(|(pos, _)| pos.y)
+                .collect();
+            baselines.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            baselines.dedup_by(|a, b| a.approx_eq(*b));
+            for &y in baselines.iter().skip(1) {
+                let pos = Point::new(indent, y - marker.baseline());
+                frame.push_frame(pos, marker.clone());
+            }
+
+            return Ok(Fragment::frame(frame));
+        }
+
+        let mut frame = self.body().layout(vt, styles, unbounded)?.into_frame();
+        let width = regions.size.x;
+        if overflow == RawOverflow::Truncate && frame.width() > width {
+            let ellipsis =
+                TextElem::packed('…').layout(vt, styles, unbounded)?.into_frame();
+            let cut = (width - ellipsis.width()).max(Abs::zero());
+            frame.set_size(Size::new(cut, frame.height()));
+            frame.clip();
+            frame.set_size(Size::new(width, frame.height()));
+            let y = frame.baseline() - ellipsis.baseline();
+            frame.push_frame(Point::new(cut, y), ellipsis);
+        }
+
+        Ok(Fragment::frame(frame))
+    }
+}
+
 /// Highlight a syntax node in a theme by calling `f` with ranges and their
 /// styles.
 fn highlight_themed<F>(
@@ -431,13 +713,28 @@ fn highlight_themed<F>(
     }
 }
 
+/// The background of a line in a diff, if it was added or removed.
+///
+/// File headers like `+++ b/file` are left as they are.
+fn diff_fill(line: &str) -> Option<Paint> {
+    if line.starts_with("+++") || line.starts_with("---") {
+        None
+    } else if line.starts_with('+') {
+        Some(Color::from_u8(0xe6, 0xff, 0xec, 0xff).into())
+    } else if line.starts_with('-') {
+        Some(Color::from_u8(0xff, 0xeb, 0xe9, 0xff).into())
+    } else {
+        None
+    }
+}
+
 /// Style a piece of text with a syntect style.
 fn styled(piece: &str, foreground: Paint, style: synt::Style) -> Content {
     let mut body = TextElem::packed(piece);
 
     let paint = to_typst(style.foreground).into();
     if paint != foreground {
-        body = body.styled(TextElem::set_fill(paint));
+        body = body.styled(TextElem::set_fill(Some(paint)));
     }
 
     if style.font_style.contains(synt::FontStyle::BOLD) {
@@ -666,3 +963,40 @@ fn align_tabs(text: &str, tab_size: usize) -> EcoString {
 
     res
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_syntax_highlights_tokens() {
+        let data = include_bytes!("../../../../assets/files/SExpressions.sublime-syntax");
+        let paths = SyntaxPaths(vec!["SExpressions.sublime-syntax".into()]);
+        let syntaxes = load_syntaxes(&paths, &[data.as_slice().into()]).unwrap();
+        let syntax = syntaxes.find_syntax_by_token("sexp").unwrap();
+
+        let mut highlighter = syntect::easy::HighlightLines::new(syntax, &THEME);
+        let pieces = highlighter.highlight_line("; a comment", &syntaxes).unwrap();
+
+        // The grammar scopes the line as a comment, which the theme colors gray.
+        let gray = synt::Color { r: 0x8a, g: 0x8a, b: 0x8a, a: 0xff };
+        assert!(pieces.iter().all(|(style, _)| style.foreground == gray));
+    }
+
+    #[test]
+    fn test_diff_fill_marks_changed_lines() {
+        let added = diff_fill("+let x = 2").unwrap();
+        let removed = diff_fill("-let x = 1").unwrap();
+        let Paint::Solid(added) = added;
+        let Paint::Solid(removed) = removed;
+        let [r, g, _, _] = added.to_vec4_u8();
+        assert!(g > r);
+        let [r, g, _, _] = removed.to_vec4_u8();
+        assert!(r > g);
+
+        assert_eq!(diff_fill(" unchanged"), None);
+        assert_eq!(diff_fill("@@ -1 +1 @@"), None);
+        assert_eq!(diff_fill("--- a/main.rs"), None);
+        assert_eq!(diff_fill("+++ b/main.rs"), None);
+    }
+}
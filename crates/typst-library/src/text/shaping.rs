@@ -538,6 +538,8 @@ struct ShapingContext<'a, 'v> {
     tags: Vec<rustybuzz::Feature>,
     fallback: bool,
     dir: Dir,
+    lang: Lang,
+    region: Option<Region>,
 }
 
 /// Shape text into [`ShapedText`].
@@ -564,6 +566,8 @@ pub fn shape<'a>(
         tags: tags(styles),
         fallback: TextElem::fallback_in(styles),
         dir,
+        lang,
+        region,
     };
 
     if !text.is_empty() {
@@ -617,7 +621,7 @@ fn shape_segment(
     if selection.is_none() && ctx.fallback {
         let first = ctx.used.first().map(Font::info);
         selection = book
-            .select_fallback(first, ctx.variant, text)
+            .select_fallback(first, ctx.variant, text, Some(ctx.lang), ctx.region)
             .and_then(|id| world.font(id))
             .filter(|font| !ctx.used.contains(font));
     }
@@ -852,6 +856,17 @@ pub fn variant(styles: StyleChain) -> FontVariant {
     variant
 }
 
+/// Find the font of the first retrievable family.
+pub fn first_font(vt: &Vt, styles: StyleChain) -> Option<Font> {
+    let world = vt.world;
+    TextElem::font_in(styles).into_iter().find_map(|family| {
+        world
+            .book()
+            .select(family.as_str(), variant(styles))
+            .and_then(|id| world.font(id))
+    })
+}
+
 /// Resolve a prioritized iterator over the font families.
 pub fn families(styles: StyleChain) -> impl Iterator<Item = FontFamily> + Clone {
     const FALLBACKS: &[&str] = &[
@@ -982,3 +997,83 @@ fn assert_glyph_ranges_in_order(glyphs: &[ShapedGlyph], dir: Dir) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shape a single zero and return its glyph id.
+    fn zero_glyph(features: &[Feature]) -> u32 {
+        let data = include_bytes!("../../../../assets/fonts/IBMPlexSerif-Regular.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let mut buffer = UnicodeBuffer::new();
+        buffer.push_str("0");
+        let output = rustybuzz::shape(font.rusty(), features, buffer);
+        output.glyph_infos()[0].glyph_id
+    }
+
+    #[test]
+    fn test_slashed_zero_changes_glyph() {
+        let mut styles = Styles::new();
+        styles.set(TextElem::set_slashed_zero(true));
+        let features = tags(StyleChain::new(&styles));
+        assert!(features.iter().any(|f| f.tag == Tag::from_bytes(b"zero")));
+        assert_ne!(zero_glyph(&features), zero_glyph(&[]));
+    }
+
+    #[test]
+    fn test_arabic_joins_by_default() {
+        let data = include_bytes!("../../../../assets/fonts/NotoSansArabic-Regular.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let shape = |text: &str, features: &[Feature]| {
+            let mut buffer = UnicodeBuffer::new();
+            buffer.push_str(text);
+            let output = rustybuzz::shape(font.rusty(), features, buffer);
+            output
+                .glyph_infos()
+                .iter()
+                .map(|info| info.glyph_id)
+                .collect::<Vec<_>>()
+        };
+
+        // Even without ligatures, the default features keep the joining forms:
+        // The beh in the middle of a word differs from an isolated one.
+        let mut styles = Styles::new();
+        styles.set(TextElem::set_ligatures(false));
+        let features = tags(StyleChain::new(&styles));
+        let isolated = shape("ب", &features);
+        let joined = shape("ببب", &features);
+        assert_eq!(joined.len(), 3);
+        assert!(joined.iter().all(|&id| id != isolated[0]));
+        assert_ne!(joined[0], joined[1]);
+        assert_ne!(joined[1], joined[2]);
+    }
+
+    #[test]
+    fn test_space_adjustability_is_bounded() {
+        let data = include_bytes!("../../../../assets/fonts/IBMPlexSerif-Regular.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let glyph = |c| ShapedGlyph {
+            font: font.clone(),
+            glyph_id: 0,
+            x_advance: Em::new(0.375),
+            x_offset: Em::zero(),
+            y_offset: Em::zero(),
+            adjustability: Adjustability::default(),
+            range: 0..1,
+            safe_to_break: true,
+            c,
+            span: (Span::detached(), 0),
+        };
+
+        // A justified space may grow by half and shrink by a third of its
+        // width. Letters can't be adjusted at all.
+        let space = glyph(' ').base_adjustability(false);
+        assert_eq!(space.stretchability, (Em::zero(), Em::new(0.1875)));
+        assert_eq!(space.shrinkability, (Em::zero(), Em::new(0.125)));
+
+        let letter = glyph('a').base_adjustability(false);
+        assert_eq!(letter.stretchability, (Em::zero(), Em::zero()));
+        assert_eq!(letter.shrinkability, (Em::zero(), Em::zero()));
+    }
+}
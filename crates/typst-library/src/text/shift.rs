@@ -1,4 +1,6 @@
-use super::{variant, SpaceElem, TextElem, TextSize};
+use typst::font::Font;
+
+use super::{first_font, SpaceElem, TextElem, TextSize};
 use crate::prelude::*;
 
 /// Renders text in subscript.
@@ -27,8 +29,18 @@ pub struct SubElem {
     /// The baseline shift for synthetic subscripts. Does not apply if
     /// `typographic` is true and the font has subscript codepoints for the
     /// given `body`.
-    #[default(Em::new(0.2).into())]
-    pub baseline: Length,
+    ///
+    /// If set to `{auto}`, the shift prescribed by the font's math constants
+    /// is used, just like for subscripts in [math]($category/math). Fonts
+    /// without math constants fall back to a shift of `{0.2em}`.
+    ///
+    /// ```example
+    /// #set text(font: "New Computer Modern Math")
+    /// x#sub(typographic: false)[2]
+    /// x#sub(typographic: false, baseline: auto)[2]
+    /// ```
+    #[default(Smart::Custom(Em::new(0.2).into()))]
+    pub baseline: Smart<Length>,
 
     /// The font size for synthetic subscripts. Does not apply if
     /// `typographic` is true and the font has subscript codepoints for the
@@ -54,8 +66,15 @@ impl Show for SubElem {
             }
         };
 
+        let baseline = self.baseline(styles).unwrap_or_else(|| {
+            first_font(vt, styles)
+                .and_then(|font| math_shift(&font, true))
+                .unwrap_or(Em::new(0.2))
+                .into()
+        });
+
         Ok(transformed.unwrap_or_else(|| {
-            body.styled(TextElem::set_baseline(self.baseline(styles)))
+            body.styled(TextElem::set_baseline(baseline))
                 .styled(TextElem::set_size(self.size(styles)))
         }))
     }
@@ -87,8 +106,18 @@ pub struct SuperElem {
     /// The baseline shift for synthetic superscripts. Does not apply if
     /// `typographic` is true and the font has superscript codepoints for the
     /// given `body`.
-    #[default(Em::new(-0.5).into())]
-    pub baseline: Length,
+    ///
+    /// If set to `{auto}`, the shift prescribed by the font's math constants
+    /// is used, just like for superscripts in [math]($category/math). Fonts
+    /// without math constants fall back to a shift of `{-0.5em}`.
+    ///
+    /// ```example
+    /// #set text(font: "New Computer Modern Math")
+    /// x#super(typographic: false)[2]
+    /// x#super(typographic: false, baseline: auto)[2]
+    /// ```
+    #[default(Smart::Custom(Em::new(-0.5).into()))]
+    pub baseline: Smart<Length>,
 
     /// The font size for synthetic superscripts. Does not apply if
     /// `typographic` is true and the font has superscript codepoints for the
@@ -114,8 +143,15 @@ impl Show for SuperElem {
             }
         };
 
+        let baseline = self.baseline(styles).unwrap_or_else(|| {
+            first_font(vt, styles)
+                .and_then(|font| math_shift(&font, false))
+                .unwrap_or(Em::new(-0.5))
+                .into()
+        });
+
         Ok(transformed.unwrap_or_else(|| {
-            body.styled(TextElem::set_baseline(self.baseline(styles)))
+            body.styled(TextElem::set_baseline(baseline))
                 .styled(TextElem::set_size(self.size(styles)))
         }))
     }
@@ -145,18 +181,19 @@ fn search_text(content: &Content, sub: bool) -> Option<EcoString> {
 /// Checks whether the first retrievable family contains all code points of the
 /// given string.
 fn is_shapable(vt: &Vt, text: &str, styles: StyleChain) -> bool {
-    let world = vt.world;
-    for family in TextElem::font_in(styles) {
-        if let Some(font) = world
-            .book()
-            .select(family.as_str(), variant(styles))
-            .and_then(|id| world.font(id))
-        {
-            return text.chars().all(|c| font.ttf().glyph_index(c).is_some());
-        }
-    }
+    first_font(vt, styles)
+        .map_or(false, |font| text.chars().all(|c| font.ttf().glyph_index(c).is_some()))
+}
 
-    false
+/// The baseline shift for a sub- or superscript as prescribed by the font's
+/// math constants, if it has any.
+fn math_shift(font: &Font, sub: bool) -> Option<Em> {
+    let constants = font.ttf().tables().math?.constants?;
+    Some(if sub {
+        font.to_em(constants.subscript_shift_down().value)
+    } else {
+        -font.to_em(constants.superscript_shift_up().value)
+    })
 }
 
 /// Convert a string to sub- or superscript codepoints if all characters
@@ -221,3 +258,25 @@ fn to_subscript_codepoint(c: char) -> Option<char> {
         _ => return None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn font(data: &'static [u8]) -> Font {
+        Font::new(data.into(), 0).unwrap()
+    }
+
+    #[test]
+    fn test_math_shift_reads_math_constants() {
+        let math = font(include_bytes!("../../../../assets/fonts/NewCMMath-Regular.otf"));
+        let sup = math_shift(&math, false).unwrap();
+        let sub = math_shift(&math, true).unwrap();
+        assert!(sup < Em::zero() && sup != Em::new(-0.5));
+        assert!(sub > Em::zero() && sub != Em::new(0.2));
+
+        let text =
+            font(include_bytes!("../../../../assets/fonts/IBMPlexSerif-Regular.ttf"));
+        assert_eq!(math_shift(&text, false), None);
+    }
+}
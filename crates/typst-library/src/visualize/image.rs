@@ -1,8 +1,11 @@
 use std::ffi::OsStr;
 use std::path::Path;
 
+use typst::diag::warning;
 use typst::geom::Smart;
-use typst::image::{Image, ImageFormat, RasterFormat, VectorFormat};
+use typst::image::{
+    Image, ImageColorSpace, ImageFormat, ImageScaling, RasterFormat, VectorFormat,
+};
 use typst::util::option_eq;
 
 use crate::compute::Readable;
@@ -14,6 +17,10 @@ use crate::text::families;
 ///
 /// Supported formats are PNG, JPEG, GIF and SVG.
 ///
+/// Text in SVG images is set with the fonts available to the document. If an
+/// SVG asks for a font family that isn't available, the current
+/// [text font]($text.font) is used instead.
+///
 /// _Note:_ Work on SVG export is ongoing and there might be visual inaccuracies
 /// in the resulting PDF. Make sure to double-check embedded SVG images. If you
 /// have an issue, also feel free to report it on [GitHub][gh-svg].
@@ -37,8 +44,18 @@ pub struct ImageElem {
     #[parse(
         let Spanned { v: path, span } =
             args.expect::<Spanned<EcoString>>("path to image file")?;
+        let fallback: Option<Content> = args.named("fallback")?;
         let id = vm.resolve_path(&path).at(span)?;
-        let data = vm.world().file(id).at(span)?;
+        let (data, fallback) = match (vm.world().file(id), fallback) {
+            (Ok(data), _) => (data, None),
+            (Err(err), Some(fallback)) => {
+                vm.vt.tracer.warn(
+                    warning!(span, "{err}").with_hint("the fallback is shown instead"),
+                );
+                (Bytes::from(vec![]), Some(fallback))
+            }
+            (Err(err), None) => Err(err).at(span)?,
+        };
         path
     )]
     pub path: EcoString,
@@ -64,6 +81,77 @@ pub struct ImageElem {
     /// How the image should adjust itself to a given area.
     #[default(ImageFit::Cover)]
     pub fit: ImageFit,
+
+    /// How the image should be scaled when it is displayed at a different
+    /// size than its natural one.
+    ///
+    /// This only affects raster images. In PDF export, it determines the
+    /// embedded image's `/Interpolate` flag. Viewers may choose to ignore it.
+    ///
+    /// ```example
+    /// #set image(width: 50%)
+    /// #image("tiger.jpg", scaling: "smooth")
+    /// #image("tiger.jpg", scaling: "pixelated")
+    /// ```
+    pub scaling: ImageScaling,
+
+    /// Whether to rotate and flip raster images according to their EXIF
+    /// orientation tag.
+    ///
+    /// Cameras often store photos in the sensor's orientation and record the
+    /// actual orientation in this tag. Set this to `{false}` to display the
+    /// pixels in their stored orientation instead.
+    #[default(true)]
+    pub exif_rotate: bool,
+
+    /// The color space to convert raster images into.
+    ///
+    /// When set to `{auto}`, images keep the color space they are stored in.
+    /// Converting to `{"gray"}` is useful for black-and-white print and
+    /// reduces the size of the exported PDF.
+    ///
+    /// ```example
+    /// #image("tiger.jpg", width: 50%, color-space: "gray")
+    /// ```
+    pub color_space: Smart<ImageColorSpace>,
+
+    /// The size of a single tile when repeating the image across its area.
+    ///
+    /// When set, the image is fitted into a tile of this size instead of its
+    /// whole area and the tile is repeated horizontally and vertically until
+    /// the area is covered. Tiles that overhang the area are cut off. Relative
+    /// sizes are resolved against the image's area.
+    ///
+    /// This is useful for decorative backgrounds.
+    ///
+    /// ```example
+    /// #set page(background: image(
+    ///   "tiger.jpg",
+    ///   width: 100%,
+    ///   height: 100%,
+    ///   tile: (25%, 20%),
+    /// ))
+    /// ```
+    pub tile: Option<Axes<Rel<Length>>>,
+
+    /// Content to show instead of the image if its file cannot be loaded.
+    ///
+    /// Without a fallback, a missing image file is an error. With one, a
+    /// warning is emitted and the fallback is shown in the image's place. This
+    /// way, drafts whose images don't exist yet still compile.
+    ///
+    /// ```typ
+    /// #image("diagram.png", fallback: rect[Diagram])
+    /// ```
+    #[external]
+    pub fallback: Option<Content>,
+
+    /// The fallback content to show because the image file couldn't be
+    /// loaded.
+    #[internal]
+    #[required]
+    #[parse(fallback)]
+    pub placeholder: Option<Content>,
 }
 
 #[scope]
@@ -99,8 +187,20 @@ impl ImageElem {
         /// How the image should adjust itself to a given area.
         #[named]
         fit: Option<ImageFit>,
+        /// How the image should be scaled.
+        #[named]
+        scaling: Option<ImageScaling>,
+        /// Whether to rotate the image according to its EXIF orientation.
+        #[named]
+        exif_rotate: Option<bool>,
+        /// The color space to convert the image into.
+        #[named]
+        color_space: Option<Smart<ImageColorSpace>>,
+        /// The size of a single tile when repeating the image.
+        #[named]
+        tile: Option<Option<Axes<Rel<Length>>>>,
     ) -> StrResult<Content> {
-        let mut elem = ImageElem::new(EcoString::new(), data);
+        let mut elem = ImageElem::new(EcoString::new(), data, None);
         if let Some(format) = format {
             elem.push_format(format);
         }
@@ -116,6 +216,18 @@ impl ImageElem {
         if let Some(fit) = fit {
             elem.push_fit(fit);
         }
+        if let Some(scaling) = scaling {
+            elem.push_scaling(scaling);
+        }
+        if let Some(exif_rotate) = exif_rotate {
+            elem.push_exif_rotate(exif_rotate);
+        }
+        if let Some(color_space) = color_space {
+            elem.push_color_space(color_space);
+        }
+        if let Some(tile) = tile {
+            elem.push_tile(tile);
+        }
         Ok(elem.pack())
     }
 }
@@ -128,6 +240,10 @@ impl Layout for ImageElem {
         styles: StyleChain,
         regions: Regions,
     ) -> SourceResult<Fragment> {
+        if let Some(placeholder) = self.placeholder() {
+            return placeholder.layout(vt, styles, regions);
+        }
+
         // Take the format that was explicitly defined, or parse the extention,
         // or try to detect the format.
         let data = self.data();
@@ -160,6 +276,9 @@ impl Layout for ImageElem {
             data.into(),
             format,
             self.alt(styles),
+            self.scaling(styles),
+            self.exif_rotate(styles),
+            self.color_space(styles),
             vt.world,
             &families(styles).map(|s| s.as_str().into()).collect::<Vec<_>>(),
         )
@@ -190,31 +309,65 @@ impl Layout for ImageElem {
             Size::new(Abs::pt(pxw), Abs::pt(pxh))
         };
 
+        // The space into which a single copy of the image is fitted: Either a
+        // tile or the whole target area.
+        let tile = self
+            .tile(styles)
+            .map(|tile| tile.resolve(styles).zip_map(target, Rel::relative_to));
+        if tile.map_or(false, |tile| !(tile.x > Abs::zero() && tile.y > Abs::zero())) {
+            bail!(self.span(), "tile size must be positive");
+        }
+        let cell = tile.unwrap_or(target);
+
+        // Find out whether the image is wider or taller than the cell.
+        let cell_wide = px_ratio > cell.x / cell.y;
+
         // Compute the actual size of the fitted image.
         let fit = self.fit(styles);
         let fitted = match fit {
             ImageFit::Cover | ImageFit::Contain => {
-                if wide == (fit == ImageFit::Contain) {
-                    Size::new(target.x, target.x / px_ratio)
+                if cell_wide == (fit == ImageFit::Contain) {
+                    Size::new(cell.x, cell.x / px_ratio)
                 } else {
-                    Size::new(target.y * px_ratio, target.y)
+                    Size::new(cell.y * px_ratio, cell.y)
                 }
             }
-            ImageFit::Stretch => target,
+            ImageFit::Stretch => cell,
         };
 
         // First, place the image in a frame of exactly its size and then resize
-        // the frame to the target size, center aligning the image in the
+        // the frame to the cell size, center aligning the image in the
         // process.
         let mut frame = Frame::new(fitted);
         frame.push(Point::zero(), FrameItem::Image(image, fitted, self.span()));
-        frame.resize(target, Axes::splat(FixedAlign::Center));
+        frame.resize(cell, Axes::splat(FixedAlign::Center));
 
         // Create a clipping group if only part of the image should be visible.
-        if fit == ImageFit::Cover && !target.fits(fitted) {
+        if fit == ImageFit::Cover && !cell.fits(fitted) {
             frame.clip();
         }
 
+        // Repeat the tile across the target area.
+        if let Some(tile) = tile {
+            if !target.is_finite() {
+                bail!(self.span(), "cannot tile an image across an infinite area");
+            }
+
+            let grid = tile_grid(tile, target);
+            if grid.x.saturating_mul(grid.y) > MAX_TILES {
+                return Err(eco_format!("image has more than {MAX_TILES} tiles"))
+                    .hint("try using a larger tile size")
+                    .at(self.span());
+            }
+
+            let mut tiled = Frame::new(target);
+            for pos in tile_positions(tile, target) {
+                tiled.push_frame(pos, frame.clone());
+            }
+            tiled.clip();
+            frame = tiled;
+        }
+
         // Apply metadata.
         frame.meta(styles, false);
 
@@ -222,6 +375,23 @@ impl Layout for ImageElem {
     }
 }
 
+/// The maximum number of tiles an image may be repeated in.
+const MAX_TILES: usize = 10_000;
+
+/// How many columns and rows of tiles are needed to cover an area.
+fn tile_grid(tile: Size, area: Size) -> Axes<usize> {
+    let count = |tile: Abs, area: Abs| (area / tile).ceil().max(1.0) as usize;
+    Axes::new(count(tile.x, area.x), count(tile.y, area.y))
+}
+
+/// The positions of the tiles that cover an area, row by row.
+fn tile_positions(tile: Size, area: Size) -> impl Iterator<Item = Point> {
+    let grid = tile_grid(tile, area);
+    (0..grid.y).flat_map(move |row| {
+        (0..grid.x).map(move |col| Point::new(tile.x * col as f64, tile.y * row as f64))
+    })
+}
+
 impl LocalName for ImageElem {
     fn local_name(&self, lang: Lang, region: Option<Region>) -> &'static str {
         match lang {
@@ -269,3 +439,23 @@ pub enum ImageFit {
     /// this means that the image will be distorted.
     Stretch,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiles_repeat_in_both_directions() {
+        let tile = Size::new(Abs::pt(10.0), Abs::pt(5.0));
+        let area = Size::new(Abs::pt(25.0), Abs::pt(10.0));
+        let positions: Vec<_> = tile_positions(tile, area)
+            .map(|pos| (pos.x.to_pt(), pos.y.to_pt()))
+            .collect();
+
+        // Three columns, the last of which overhangs the area, and two rows.
+        assert_eq!(
+            positions,
+            [(0.0, 0.0), (10.0, 0.0), (20.0, 0.0), (0.0, 5.0), (10.0, 5.0), (20.0, 5.0)],
+        );
+    }
+}
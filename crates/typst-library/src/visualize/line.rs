@@ -1,3 +1,4 @@
+use super::default_stroke;
 use crate::prelude::*;
 
 /// A line from one point to another.
@@ -49,6 +50,26 @@ pub struct LineElem {
     #[resolve]
     #[fold]
     pub stroke: Stroke,
+
+    /// The marks to draw at the ends of the line, such as arrowheads.
+    ///
+    /// This can be a string like `{"->"}`, `{"<-"}`, or `{"<->"}` which places
+    /// arrowheads at the end, the start, or both ends of the line, or a
+    /// dictionary with the keys `start` and `end`. Each mark can be `{none}`,
+    /// one of the kinds `{"arrow"}`, `{"bar"}`, and `{"circle"}`, or a
+    /// dictionary with the keys `kind` and `size`. By default, a mark is six
+    /// times as large as the stroke is thick. Marks are oriented along the
+    /// line.
+    ///
+    /// ```example
+    /// #line(length: 100%, marks: "->")
+    /// #line(length: 100%, marks: "<->", stroke: 2pt)
+    /// #line(length: 100%, marks: (
+    ///   start: "bar",
+    ///   end: (kind: "circle", size: 8pt),
+    /// ))
+    /// ```
+    pub marks: Marks,
 }
 
 impl Layout for LineElem {
@@ -71,13 +92,26 @@ impl Layout for LineElem {
                 resolve(Axes::new(x, y))
             });
 
-        let stroke = self.stroke(styles).unwrap_or_default();
+        let stroke = self.stroke(styles).unwrap_or(default_stroke(styles));
         let size = start.max(start + delta).max(Size::zero());
         let target = regions.expand.select(regions.size, size);
 
         let mut frame = Frame::new(target);
+        let marks = self.marks(styles);
+        let mark_shapes = [
+            marks.start.and_then(|mark| {
+                mark.shape(start.to_point(), -delta.to_point(), &stroke, styles)
+            }),
+            marks.end.and_then(|mark| {
+                mark.shape((start + delta).to_point(), delta.to_point(), &stroke, styles)
+            }),
+        ];
+
         let shape = Geometry::Line(delta.to_point()).stroked(stroke);
         frame.push(start.to_point(), FrameItem::Shape(shape, self.span()));
+        for (pos, shape) in mark_shapes.into_iter().flatten() {
+            frame.push(pos, FrameItem::Shape(shape, self.span()));
+        }
         Ok(Fragment::frame(frame))
     }
 }
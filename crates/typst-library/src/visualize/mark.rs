@@ -0,0 +1,210 @@
+use typst::eval::NoneValue;
+
+use crate::prelude::*;
+
+/// Marks drawn at the ends of a line or path.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Marks {
+    /// The mark at the start.
+    pub start: Option<Mark>,
+    /// The mark at the end.
+    pub end: Option<Mark>,
+}
+
+cast! {
+    Marks,
+    self => dict! {
+        "start" => self.start.into_value(),
+        "end" => self.end.into_value(),
+    }.into_value(),
+    _: NoneValue => Self::default(),
+    v: Str => {
+        let arrow = Some(Mark::default());
+        match v.as_str() {
+            "-" => Self::default(),
+            "->" => Self { start: None, end: arrow },
+            "<-" => Self { start: arrow, end: None },
+            "<->" => Self { start: arrow, end: arrow },
+            _ => bail!(r#"expected "-", "->", "<-", or "<->""#),
+        }
+    },
+    mut dict: Dict => {
+        let mut take = |key| dict.take(key).ok().map(Value::cast).transpose();
+        let marks = Self {
+            start: take("start")?.flatten(),
+            end: take("end")?.flatten(),
+        };
+        dict.finish(&["start", "end"])?;
+        marks
+    },
+}
+
+/// A mark drawn at one end of a line or path.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Mark {
+    /// The mark's shape.
+    pub kind: MarkKind,
+    /// The mark's size. Defaults to six times the stroke's thickness.
+    pub size: Smart<Length>,
+}
+
+impl Mark {
+    /// Create the shape for this mark, with its tip at `tip`, pointing into
+    /// the direction `dir`. Returns the shape and its position.
+    pub fn shape(
+        self,
+        tip: Point,
+        dir: Point,
+        stroke: &FixedStroke,
+        styles: StyleChain,
+    ) -> Option<(Point, Shape)> {
+        let length = dir.hypot();
+        if length.to_raw() == 0.0 {
+            return None;
+        }
+
+        let size = self
+            .size
+            .map(|size| size.resolve(styles))
+            .unwrap_or(stroke.thickness * 6.0);
+        let dir = dir / length.to_raw();
+        let normal = Point::new(-dir.y, dir.x);
+        let stroke = FixedStroke {
+            dash_pattern: None,
+            line_join: LineJoin::Miter,
+            ..stroke.clone()
+        };
+
+        Some(match self.kind {
+            MarkKind::Arrow => {
+                let base = tip - dir * size.to_raw();
+                let wing = normal * (0.4 * size.to_raw());
+                let mut path = Path::new();
+                path.move_to(tip);
+                path.line_to(base + wing);
+                path.line_to(base - wing);
+                path.close_path();
+                let shape = Shape {
+                    geometry: Geometry::Path(path),
+                    fill: Some(stroke.paint.clone()),
+                    fill_rule: FillRule::default(),
+                    stroke: Some(stroke),
+                };
+                (Point::zero(), shape)
+            }
+            MarkKind::Bar => {
+                let wing = normal * (0.5 * size.to_raw());
+                let shape = Geometry::Line(wing * 2.0).stroked(stroke);
+                (tip - wing, shape)
+            }
+            MarkKind::Circle => {
+                let radius = size / 3.0;
+                let fill = Some(stroke.paint.clone());
+                let shape = ellipse(Size::splat(radius * 2.0), fill, Some(stroke));
+                (tip - Point::splat(radius), shape)
+            }
+        })
+    }
+}
+
+cast! {
+    Mark,
+    self => match self.size {
+        Smart::Auto => self.kind.into_value(),
+        Smart::Custom(size) => dict! {
+            "kind" => self.kind.into_value(),
+            "size" => size.into_value(),
+        }.into_value(),
+    },
+    kind: MarkKind => Self { kind, size: Smart::Auto },
+    mut dict: Dict => {
+        let kind = dict.take("kind").ok().map(Value::cast).transpose()?;
+        let size = dict.take("size").ok().map(Value::cast).transpose()?;
+        dict.finish(&["kind", "size"])?;
+        Self {
+            kind: kind.unwrap_or_default(),
+            size: size.unwrap_or_default(),
+        }
+    },
+}
+
+/// The shape of a mark.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum MarkKind {
+    /// A filled triangle whose tip sits on the end point.
+    #[default]
+    Arrow,
+    /// A short bar across the end point.
+    Bar,
+    /// A filled circle centered on the end point.
+    Circle,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrow_points_along_line() {
+        let styles = Styles::new();
+        let stroke = FixedStroke::default();
+        let tip = Point::new(Abs::pt(30.0), Abs::pt(10.0));
+        let dir = Point::with_x(Abs::pt(30.0));
+        let (pos, shape) = Mark::default()
+            .shape(tip, dir, &stroke, StyleChain::new(&styles))
+            .unwrap();
+        assert_eq!(pos, Point::zero());
+        assert_eq!(shape.fill, Some(stroke.paint.clone()));
+
+        let Geometry::Path(path) = shape.geometry else { panic!("expected a path") };
+        let points: Vec<Point> = path
+            .0
+            .iter()
+            .filter_map(|item| match *item {
+                PathItem::MoveTo(p) | PathItem::LineTo(p) => Some(p),
+                _ => None,
+            })
+            .collect();
+
+        // A triangle with its tip at the end of the line and its base six
+        // stroke widths behind it, symmetric around the line.
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0], tip);
+        for base in &points[1..] {
+            assert!((base.x - Abs::pt(24.0)).to_pt().abs() < 1e-6);
+        }
+        assert!(((points[1].y - tip.y) + (points[2].y - tip.y)).to_pt().abs() < 1e-6);
+        assert!((points[1].y - points[2].y).to_pt().abs() > 1.0);
+    }
+
+    #[test]
+    fn test_arrow_follows_diagonal_line() {
+        let styles = Styles::new();
+        let stroke = FixedStroke::default();
+        let tip = Point::new(Abs::pt(40.0), Abs::pt(30.0));
+        let dir = Point::new(Abs::pt(40.0), Abs::pt(30.0));
+        let (_, shape) = Mark::default()
+            .shape(tip, dir, &stroke, StyleChain::new(&styles))
+            .unwrap();
+
+        let Geometry::Path(path) = shape.geometry else { panic!("expected a path") };
+        let points: Vec<Point> = path
+            .0
+            .iter()
+            .filter_map(|item| match *item {
+                PathItem::MoveTo(p) | PathItem::LineTo(p) => Some(p),
+                _ => None,
+            })
+            .collect();
+
+        // The middle of the base lies on the line, one mark size behind the
+        // tip, and the base is perpendicular to the line.
+        let size = stroke.thickness.to_pt() * 6.0;
+        let mid = (points[1] + points[2]) / 2.0;
+        assert!((mid.x.to_pt() - (40.0 - 0.8 * size)).abs() < 1e-6);
+        assert!((mid.y.to_pt() - (30.0 - 0.6 * size)).abs() < 1e-6);
+        let base = points[1] - points[2];
+        let dot = base.x.to_pt() * dir.x.to_pt() + base.y.to_pt() * dir.y.to_pt();
+        assert!(dot.abs() < 1e-6);
+    }
+}
@@ -2,23 +2,27 @@
 
 mod image;
 mod line;
+mod mark;
 mod path;
 mod polygon;
 mod shape;
 
 pub use self::image::*;
 pub use self::line::*;
+pub use self::mark::*;
 pub use self::path::*;
 pub use self::polygon::*;
 pub use self::shape::*;
 
 use crate::prelude::*;
+use crate::text::TextElem;
 
 /// Hook up all visualize definitions.
 pub(super) fn define(global: &mut Scope) {
     global.category("visualize");
     global.define_type::<Color>();
     global.define_type::<Stroke>();
+    global.define_type::<Coord>();
     global.define_elem::<ImageElem>();
     global.define_elem::<LineElem>();
     global.define_elem::<RectElem>();
@@ -28,3 +32,13 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<PolygonElem>();
     global.define_elem::<PathElem>();
 }
+
+/// The stroke that drawing elements fall back to when no paint or other
+/// properties are specified: A one point thick line in the color of the
+/// surrounding text.
+fn default_stroke(styles: StyleChain) -> FixedStroke {
+    match TextElem::fill_in(styles) {
+        Some(paint) => FixedStroke { paint, ..FixedStroke::default() },
+        None => FixedStroke::default(),
+    }
+}
@@ -1,6 +1,7 @@
 use kurbo::{CubicBez, ParamCurveExtrema};
 use typst::eval::Reflect;
 
+use super::default_stroke;
 use crate::prelude::*;
 
 use PathVertex::{AllControlPoints, MirroredControlPoint, Vertex};
@@ -25,10 +26,36 @@ pub struct PathElem {
     /// When setting a fill, the default stroke disappears. To create a
     /// rectangle with both fill and stroke, you have to configure both.
     ///
-    /// Currently all paths are filled according to the [non-zero winding
-    /// rule](https://en.wikipedia.org/wiki/Nonzero-rule).
+    /// Which parts of the path count as its interior is determined by the
+    /// [`fill-rule`]($path.fill-rule).
     pub fill: Option<Paint>,
 
+    /// The rule used to fill the path.
+    ///
+    /// - `{"non-zero"}`: Fills everything the path winds around, taking its
+    ///   direction into account. Overlapping parts of the path are filled
+    ///   solid. This is also known as the
+    ///   [non-zero winding rule](https://en.wikipedia.org/wiki/Nonzero-rule).
+    /// - `{"even-odd"}`: Fills only areas that are enclosed an odd number of
+    ///   times. Overlapping parts of the path leave holes. This is also known
+    ///   as the [even-odd rule](https://en.wikipedia.org/wiki/Even-odd_rule).
+    ///
+    /// ```example
+    /// #path(
+    ///   fill: blue.lighten(80%),
+    ///   fill-rule: "even-odd",
+    ///   closed: true,
+    ///   (0pt, 0pt),
+    ///   (40pt, 0pt),
+    ///   (40pt, 40pt),
+    ///   (10pt, 10pt),
+    ///   (30pt, 10pt),
+    ///   (0pt, 40pt),
+    /// )
+    /// ```
+    #[default]
+    pub fill_rule: FillRule,
+
     /// How to [stroke]($stroke) the path. This can be:
     ///
     /// Can be set to  `{none}` to disable the stroke or to `{auto}` for a
@@ -44,6 +71,20 @@ pub struct PathElem {
     #[default(false)]
     pub closed: bool,
 
+    /// The marks to draw at the ends of the path, such as arrowheads. Takes
+    /// the same values as the [`marks`]($line.marks) of a line. Marks are
+    /// oriented along the path's direction at its ends and are not drawn for
+    /// closed paths.
+    ///
+    /// ```example
+    /// #path(
+    ///   marks: "->",
+    ///   (0pt, 0pt),
+    ///   ((40pt, 30pt), (-20pt, 0pt)),
+    /// )
+    /// ```
+    pub marks: Marks,
+
     /// The vertices of the path.
     ///
     /// Each vertex can be defined in 3 ways:
@@ -120,7 +161,8 @@ impl Layout for PathElem {
             add_cubic(from_point, to_point, from, to);
         }
 
-        if self.closed(styles) {
+        let closed = self.closed(styles);
+        if closed {
             let from = *vertices.last().unwrap(); // We checked that we have at least one element.
             let to = vertices[0];
             let from_point = *points.last().unwrap();
@@ -133,14 +175,49 @@ impl Layout for PathElem {
         // Prepare fill and stroke.
         let fill = self.fill(styles);
         let stroke = match self.stroke(styles) {
-            Smart::Auto if fill.is_none() => Some(FixedStroke::default()),
+            Smart::Auto if fill.is_none() => Some(default_stroke(styles)),
             Smart::Auto => None,
-            Smart::Custom(stroke) => stroke.map(Stroke::unwrap_or_default),
+            Smart::Custom(stroke) => stroke.map(|s| s.unwrap_or(default_stroke(styles))),
         };
 
+        // Orient the marks along the tangents at the ends of the path, which
+        // point towards the control points unless those coincide with the
+        // vertices.
+        let mut mark_shapes = vec![];
+        let tangent = |control: Axes<Rel<Length>>, neighbour: Point, point: Point| {
+            let control = resolve(control);
+            if control.hypot().to_raw() != 0.0 {
+                control
+            } else {
+                neighbour - point
+            }
+        };
+
+        let n = points.len();
+        if let Some(stroke) = stroke.as_ref().filter(|_| !closed && n > 1) {
+            let marks = self.marks(styles);
+            if let Some(mark) = marks.start {
+                let dir = tangent(vertices[0].control_point_from(), points[1], points[0]);
+                mark_shapes.extend(mark.shape(points[0], -dir, stroke, styles));
+            }
+            if let Some(mark) = marks.end {
+                let control = vertices[n - 1].control_point_to();
+                let dir = tangent(control, points[n - 2], points[n - 1]);
+                mark_shapes.extend(mark.shape(points[n - 1], -dir, stroke, styles));
+            }
+        }
+
         let mut frame = Frame::new(size);
-        let shape = Shape { geometry: Geometry::Path(path), stroke, fill };
+        let shape = Shape {
+            geometry: Geometry::Path(path),
+            stroke,
+            fill,
+            fill_rule: self.fill_rule(styles),
+        };
         frame.push(Point::zero(), FrameItem::Shape(shape, self.span()));
+        for (pos, shape) in mark_shapes {
+            frame.push(pos, FrameItem::Shape(shape, self.span()));
+        }
 
         Ok(Fragment::frame(frame))
     }
@@ -186,6 +263,7 @@ cast! {
         MirroredControlPoint(x, c) => array![x, c].into_value(),
         AllControlPoints(x, c1, c2) => array![x, c1, c2].into_value(),
     },
+    coord: Coord => Vertex(coord.into()),
     array: Array => {
         let mut iter = array.into_iter();
         match (iter.next(), iter.next(), iter.next(), iter.next()) {
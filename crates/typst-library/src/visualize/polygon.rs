@@ -1,5 +1,6 @@
 use std::f64::consts::PI;
 
+use super::default_stroke;
 use crate::prelude::*;
 
 /// A closed polygon.
@@ -24,10 +25,24 @@ pub struct PolygonElem {
     /// When setting a fill, the default stroke disappears. To create a
     /// rectangle with both fill and stroke, you have to configure both.
     ///
-    /// Currently all polygons are filled according to the
-    /// [non-zero winding rule](https://en.wikipedia.org/wiki/Nonzero-rule).
+    /// Which parts of the polygon count as its interior is determined by the
+    /// [`fill-rule`]($polygon.fill-rule).
     pub fill: Option<Paint>,
 
+    /// The rule used to fill the polygon. See the
+    /// [path's documentation]($path.fill-rule) for more details.
+    ///
+    /// ```example
+    /// #let star = (
+    ///   (15pt, 0pt), (24pt, 30pt), (0pt, 11pt),
+    ///   (30pt, 11pt), (6pt, 30pt),
+    /// )
+    /// #polygon(fill: blue, ..star)
+    /// #polygon(fill: blue, fill-rule: "even-odd", ..star)
+    /// ```
+    #[default]
+    pub fill_rule: FillRule,
+
     /// How to [stroke]($stroke) the polygon. This can be:
     ///
     /// Can be set to  `{none}` to disable the stroke or to `{auto}` for a
@@ -140,9 +155,9 @@ impl Layout for PolygonElem {
         // Prepare fill and stroke.
         let fill = self.fill(styles);
         let stroke = match self.stroke(styles) {
-            Smart::Auto if fill.is_none() => Some(FixedStroke::default()),
+            Smart::Auto if fill.is_none() => Some(default_stroke(styles)),
             Smart::Auto => None,
-            Smart::Custom(stroke) => stroke.map(Stroke::unwrap_or_default),
+            Smart::Custom(stroke) => stroke.map(|s| s.unwrap_or(default_stroke(styles))),
         };
 
         // Construct a closed path given all points.
@@ -153,7 +168,12 @@ impl Layout for PolygonElem {
         }
         path.close_path();
 
-        let shape = Shape { geometry: Geometry::Path(path), stroke, fill };
+        let shape = Shape {
+            geometry: Geometry::Path(path),
+            stroke,
+            fill,
+            fill_rule: self.fill_rule(styles),
+        };
         frame.push(Point::zero(), FrameItem::Shape(shape, self.span()));
 
         Ok(Fragment::frame(frame))
@@ -1,5 +1,7 @@
 use std::f64::consts::SQRT_2;
 
+use super::default_stroke;
+use crate::layout::text_inset;
 use crate::prelude::*;
 
 /// A rectangle with optional content.
@@ -36,8 +38,8 @@ pub struct RectElem {
     /// How to stroke the rectangle. This can be:
     ///
     /// - `{none}` to disable stroking
-    /// - `{auto}` for a stroke of `{1pt + black}` if and if only if no fill is
-    ///   given.
+    /// - `{auto}` for a `{1pt}` thick stroke in the text's color if and if only
+    ///   if no fill is given.
     /// - Any kind of [stroke]($stroke)
     /// - A dictionary describing the stroke for each side inidvidually. The
     ///   dictionary can contain the following keys in order of precedence:
@@ -57,6 +59,7 @@ pub struct RectElem {
     ///   rect(stroke: red),
     ///   rect(stroke: 2pt),
     ///   rect(stroke: 2pt + red),
+    ///   rect(stroke: (left: 2pt + red, bottom: 1pt)),
     /// )
     /// ```
     #[resolve]
@@ -102,11 +105,11 @@ pub struct RectElem {
     pub radius: Corners<Option<Rel<Length>>>,
 
     /// How much to pad the rectangle's content.
-    /// See the [box's documentation]($box.outset) for more details.
+    /// See the [box's documentation]($box.inset) for more details.
     #[resolve]
     #[fold]
-    #[default(Sides::splat(Abs::pt(5.0).into()))]
-    pub inset: Sides<Option<Rel<Length>>>,
+    #[default(Smart::Custom(Sides::splat(Abs::pt(5.0).into())))]
+    pub inset: Smart<Sides<Option<Rel<Length>>>>,
 
     /// How much to expand the rectangle's size without affecting the layout.
     /// See the [box's documentation]($box.outset) for more details.
@@ -130,6 +133,7 @@ impl Layout for RectElem {
         styles: StyleChain,
         regions: Regions,
     ) -> SourceResult<Fragment> {
+        let inset = self.inset(styles).unwrap_or_else(|| text_inset(vt, styles));
         layout(
             vt,
             styles,
@@ -139,7 +143,7 @@ impl Layout for RectElem {
             Axes::new(self.width(styles), self.height(styles)),
             self.fill(styles),
             self.stroke(styles),
-            self.inset(styles),
+            inset,
             self.outset(styles),
             self.radius(styles),
             self.span(),
@@ -497,9 +501,11 @@ fn layout(
 
     // Prepare stroke.
     let stroke = match stroke {
-        Smart::Auto if fill.is_none() => Sides::splat(Some(FixedStroke::default())),
+        Smart::Auto if fill.is_none() => Sides::splat(Some(default_stroke(styles))),
         Smart::Auto => Sides::splat(None),
-        Smart::Custom(strokes) => strokes.map(|s| s.map(Stroke::unwrap_or_default)),
+        Smart::Custom(strokes) => {
+            strokes.map(|s| s.map(|s| s.unwrap_or(default_stroke(styles))))
+        }
     };
 
     // Add fill and/or stroke.
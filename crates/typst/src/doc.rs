@@ -8,7 +8,7 @@ use std::sync::Arc;
 
 use ecow::EcoString;
 
-use crate::eval::{cast, dict, ty, Dict, Value};
+use crate::eval::{cast, dict, ty, Bytes, Datetime, Dict, Value};
 use crate::export::PdfPageLabel;
 use crate::font::Font;
 use crate::geom::{
@@ -30,6 +30,13 @@ pub struct Document {
     pub author: Vec<EcoString>,
     /// The document's keywords.
     pub keywords: Vec<EcoString>,
+    /// The document's creation date.
+    pub date: Option<Datetime>,
+    /// Files to embed into the document, with their names.
+    pub attachments: Vec<(EcoString, Bytes)>,
+    /// The depth from which on items in the PDF outline are collapsed. If
+    /// `None`, all items are expanded.
+    pub outline_collapse: Option<NonZeroUsize>,
 }
 
 /// A finished layout with items at fixed positions.
@@ -470,8 +477,9 @@ pub struct TextItem {
     pub font: Font,
     /// The font size.
     pub size: Abs,
-    /// Glyph color.
-    pub fill: Paint,
+    /// Glyph color. If this is `None`, the text is invisible, but still
+    /// present in the document.
+    pub fill: Option<Paint>,
     /// The natural language of the text.
     pub lang: Lang,
     /// The item's plain text.
@@ -668,8 +676,9 @@ cast! {
 #[ty]
 #[derive(Clone, PartialEq, Hash)]
 pub enum Meta {
-    /// An internal or external link to a destination.
-    Link(Destination),
+    /// An internal or external link to a destination, with an optional
+    /// tooltip that viewers show on hover.
+    Link(Destination, Option<EcoString>),
     /// An identifiable element that produces something within the area this
     /// metadata is attached to.
     Elem(Content),
@@ -690,7 +699,7 @@ cast! {
 impl Debug for Meta {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
-            Self::Link(dest) => write!(f, "Link({dest:?})"),
+            Self::Link(dest, _) => write!(f, "Link({dest:?})"),
             Self::Elem(content) => write!(f, "Elem({:?})", content.func()),
             Self::PageNumbering(value) => write!(f, "PageNumbering({value:?})"),
             Self::PdfPageLabel(label) => write!(f, "PdfPageLabel({label:?})"),
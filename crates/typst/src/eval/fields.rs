@@ -1,7 +1,7 @@
 use ecow::{eco_format, EcoString};
 
 use crate::diag::StrResult;
-use crate::geom::{Align, Length, Rel, Stroke};
+use crate::geom::{Align, Coord, Length, Rel, Stroke};
 
 use super::{IntoValue, Type, Value};
 
@@ -45,6 +45,12 @@ pub(crate) fn field(value: &Value, field: &str) -> StrResult<Value> {
                     "y" => align.y().into_value(),
                     _ => return missing(),
                 }
+            } else if let Some(coord) = dynamic.downcast::<Coord>() {
+                match field {
+                    "x" => coord.x.into_value(),
+                    "y" => coord.y.into_value(),
+                    _ => return missing(),
+                }
             } else {
                 return nope();
             }
@@ -75,7 +81,7 @@ pub fn fields_on(ty: Type) -> &'static [&'static str] {
         &["ratio", "length"]
     } else if ty == Type::of::<Stroke>() {
         &["paint", "thickness", "cap", "join", "dash", "miter-limit"]
-    } else if ty == Type::of::<Align>() {
+    } else if ty == Type::of::<Align>() || ty == Type::of::<Coord>() {
         &["x", "y"]
     } else {
         &[]
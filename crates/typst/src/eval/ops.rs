@@ -7,7 +7,7 @@ use ecow::eco_format;
 
 use super::{format_str, IntoValue, Regex, Value};
 use crate::diag::{bail, StrResult};
-use crate::geom::{Align, Length, Numeric, Rel, Smart, Stroke};
+use crate::geom::{Align, Coord, Length, Numeric, Rel, Smart, Stroke};
 use Value::*;
 
 /// Bail with a type mismatch error.
@@ -68,6 +68,10 @@ pub fn neg(value: Value) -> StrResult<Value> {
         Relative(v) => Relative(-v),
         Fraction(v) => Fraction(-v),
         Duration(v) => Duration(-v),
+        Dyn(v) => match v.downcast::<Coord>() {
+            Some(&coord) => (-coord).into_value(),
+            None => mismatch!("cannot apply '-' to {}", v),
+        },
         v => mismatch!("cannot apply '-' to {}", v),
     })
 }
@@ -134,6 +138,11 @@ pub fn add(lhs: Value, rhs: Value) -> StrResult<Value> {
                 return Ok((a + b)?.into_value());
             }
 
+            // Coordinates can be summed.
+            if let (Some(&a), Some(&b)) = (a.downcast::<Coord>(), b.downcast::<Coord>()) {
+                return Ok((a + b).into_value());
+            }
+
             mismatch!("cannot add {} and {}", a, b);
         }
 
@@ -169,6 +178,15 @@ pub fn sub(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Datetime(a), Duration(b)) => Datetime(a - b),
         (Datetime(a), Datetime(b)) => Duration((a - b)?),
 
+        (Dyn(a), Dyn(b)) => {
+            // Coordinates can be subtracted.
+            if let (Some(&a), Some(&b)) = (a.downcast::<Coord>(), b.downcast::<Coord>()) {
+                return Ok((a - b).into_value());
+            }
+
+            mismatch!("cannot subtract {1} from {0}", a, b);
+        }
+
         (a, b) => mismatch!("cannot subtract {1} from {0}", a, b),
     })
 }
@@ -5,7 +5,7 @@ use image::{DynamicImage, GenericImageView, Rgba};
 use pdf_writer::{Filter, Finish};
 
 use super::{deflate, PdfContext, RefExt};
-use crate::image::{ImageKind, RasterFormat, RasterImage};
+use crate::image::{ImageKind, ImageScaling, RasterFormat, RasterImage};
 
 /// Embed all used images into the PDF.
 #[tracing::instrument(skip_all)]
@@ -17,6 +17,7 @@ pub fn write_images(ctx: &mut PdfContext) {
 
         let width = image.width();
         let height = image.height();
+        let scaling = image.scaling();
 
         // Add the primary image.
         match image.kind() {
@@ -28,6 +29,7 @@ pub fn write_images(ctx: &mut PdfContext) {
                 image.width(width as i32);
                 image.height(height as i32);
                 image.bits_per_component(8);
+                image.interpolate(scaling == ImageScaling::Smooth);
 
                 let space = image.color_space();
                 if raster.icc().is_some() {
@@ -52,6 +54,7 @@ pub fn write_images(ctx: &mut PdfContext) {
                     mask.height(height as i32);
                     mask.color_space().device_gray();
                     mask.bits_per_component(8);
+                    mask.interpolate(scaling == ImageScaling::Smooth);
                 } else {
                     image.finish();
                 }
@@ -111,12 +114,19 @@ fn encode_image(image: &RasterImage) -> (Arc<Vec<u8>>, Filter, bool) {
 
         // TODO: Encode flate streams with PNG-predictor?
 
-        // 8-bit gray PNG.
-        (RasterFormat::Png, DynamicImage::ImageLuma8(luma)) => {
+        // 8-bit gray PNG or GIF.
+        (_, DynamicImage::ImageLuma8(luma)) => {
             let data = deflate(luma.as_raw());
             (data.into(), Filter::FlateDecode, false)
         }
 
+        // 8-bit gray with alpha.
+        (_, DynamicImage::ImageLumaA8(luma)) => {
+            let pixels: Vec<_> = luma.pixels().map(|p| p.0[0]).collect();
+            let data = deflate(&pixels);
+            (data.into(), Filter::FlateDecode, false)
+        }
+
         // Anything else (including Rgb(a) PNGs).
         (_, buf) => {
             let (width, height) = buf.dimensions();
@@ -144,3 +154,26 @@ fn encode_alpha(raster: &RasterImage) -> (Arc<Vec<u8>>, Filter) {
         .collect();
     (Arc::new(deflate(&pixels)), Filter::FlateDecode)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Smart;
+    use crate::image::ImageColorSpace;
+
+    #[test]
+    fn test_gray_images_use_gray_color_space() {
+        let data = include_bytes!("../../../../../assets/files/rhino.png");
+        let decode = |space| {
+            RasterImage::new(data.to_vec().into(), RasterFormat::Png, true, space)
+                .unwrap()
+        };
+
+        let (_, _, has_color) = encode_image(&decode(Smart::Auto));
+        assert!(has_color);
+
+        let (_, _, has_color) =
+            encode_image(&decode(Smart::Custom(ImageColorSpace::Gray)));
+        assert!(!has_color);
+    }
+}
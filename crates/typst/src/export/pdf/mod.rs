@@ -17,12 +17,13 @@ use std::num::NonZeroUsize;
 
 use ecow::{eco_format, EcoString};
 use pdf_writer::types::Direction;
-use pdf_writer::writers::PageLabel;
-use pdf_writer::{Finish, Name, PdfWriter, Ref, TextStr};
+use pdf_writer::writers::{self, PageLabel};
+use pdf_writer::{Filter, Finish, Name, PdfWriter, Ref, Str, TextStr};
 use xmp_writer::{LangId, RenditionClass, XmpWriter};
 
 use self::page::Page;
-use crate::doc::{Document, Lang};
+use crate::doc::{Document, Lang, Position};
+use crate::eval::Datetime;
 use crate::font::Font;
 use crate::geom::{Abs, Dir, Em};
 use crate::image::Image;
@@ -32,7 +33,10 @@ use extg::ExternalGraphicsState;
 
 /// Export a document into a PDF file.
 ///
-/// Returns the raw bytes making up the PDF file.
+/// Returns the raw bytes making up the PDF file. The output only depends on
+/// the document: Exporting the same document twice yields identical bytes. To
+/// keep the output stable across compilations, the document's date must be
+/// fixed or `{none}`.
 #[tracing::instrument(skip_all)]
 pub fn pdf(document: &Document) -> Vec<u8> {
     let mut ctx = PdfContext::new(document);
@@ -62,6 +66,9 @@ pub struct PdfContext<'a> {
     font_map: Remapper<Font>,
     image_map: Remapper<Image>,
     ext_gs_map: Remapper<ExternalGraphicsState>,
+    /// The targets of internal links. Each distinct target is written once as a
+    /// named destination, which all links to it refer to.
+    dest_map: Remapper<Position>,
     /// For each font a mapping from used glyphs to their text representation.
     /// May contain multiple chars in case of ligatures or similar things. The
     /// same glyph can have a different text representation within one document,
@@ -92,6 +99,7 @@ impl<'a> PdfContext<'a> {
             font_map: Remapper::new(),
             image_map: Remapper::new(),
             ext_gs_map: Remapper::new(),
+            dest_map: Remapper::new(),
             glyph_sets: HashMap::new(),
             languages: HashMap::new(),
         }
@@ -119,6 +127,12 @@ fn write_catalog(ctx: &mut PdfContext) {
     // Write the page labels.
     let page_labels = write_page_labels(ctx);
 
+    // Write the embedded files.
+    let attachments = write_attachments(ctx);
+
+    // Write the named destinations.
+    let dests = write_named_destinations(ctx);
+
     // Write the document information.
     let mut info = ctx.writer.document_info(ctx.alloc.bump());
     let mut xmp = XmpWriter::new();
@@ -142,12 +156,21 @@ fn write_catalog(ctx: &mut PdfContext) {
         let joined = keywords.join(", ");
         info.keywords(TextStr(&joined));
         xmp.pdf_keywords(&joined);
+        xmp.subject(keywords.iter().map(|s| s.as_str()));
+    }
+
+    if let Some(date) = ctx.document.date.and_then(pdf_date) {
+        info.creation_date(date);
+        info.modified_date(date);
     }
 
     info.finish();
     xmp.num_pages(ctx.document.pages.len() as u32);
     xmp.format("application/pdf");
-    xmp.language(ctx.languages.keys().map(|lang| LangId(lang.as_str())));
+    // Sort the languages so that the output is reproducible.
+    let mut languages: Vec<_> = ctx.languages.keys().collect();
+    languages.sort();
+    xmp.language(languages.into_iter().map(|lang| LangId(lang.as_str())));
     xmp.rendition_class(RenditionClass::Proof);
     xmp.pdf_version("1.7");
 
@@ -173,6 +196,30 @@ fn write_catalog(ctx: &mut PdfContext) {
         }
     }
 
+    // Insert the named destinations and embedded files into the name
+    // dictionary.
+    if !dests.is_empty() || !attachments.is_empty() {
+        let mut names = catalog.insert(Name(b"Names")).dict();
+
+        if !dests.is_empty() {
+            let mut tree = names.insert(Name(b"Dests")).dict();
+            let mut entries = tree.insert(Name(b"Names")).array();
+            for (name, r) in &dests {
+                entries.item(Str(name.as_bytes()));
+                entries.item(*r);
+            }
+        }
+
+        if !attachments.is_empty() {
+            let mut tree = names.insert(Name(b"EmbeddedFiles")).dict();
+            let mut entries = tree.insert(Name(b"Names")).array();
+            for (name, r) in &attachments {
+                entries.item(TextStr(name));
+                entries.item(*r);
+            }
+        }
+    }
+
     if let Some(outline_root_id) = outline_root_id {
         catalog.outlines(outline_root_id);
     }
@@ -182,6 +229,94 @@ fn write_catalog(ctx: &mut PdfContext) {
     }
 }
 
+/// Convert a datetime into a PDF date, if its year is representable.
+fn pdf_date(datetime: Datetime) -> Option<pdf_writer::Date> {
+    let year = u16::try_from(datetime.year()?).ok()?;
+    let mut date = pdf_writer::Date::new(year);
+    if let Some(month) = datetime.month() {
+        date = date.month(month);
+    }
+    if let Some(day) = datetime.day() {
+        date = date.day(day);
+    }
+    if let Some(hour) = datetime.hour() {
+        date = date.hour(hour);
+    }
+    if let Some(minute) = datetime.minute() {
+        date = date.minute(minute);
+    }
+    if let Some(second) = datetime.second() {
+        date = date.second(second);
+    }
+    Some(date)
+}
+
+/// Write the embedded files and their file specifications.
+///
+/// Returns the file specifications sorted by name, as required for the
+/// entries of a name tree.
+#[tracing::instrument(skip_all)]
+fn write_attachments(ctx: &mut PdfContext) -> Vec<(EcoString, Ref)> {
+    let mut result = vec![];
+
+    for (name, data) in &ctx.document.attachments {
+        let file_ref = ctx.alloc.bump();
+        let compressed = deflate(data);
+        let mut file = ctx.writer.stream(file_ref, &compressed);
+        file.pair(Name(b"Type"), Name(b"EmbeddedFile"));
+        file.filter(Filter::FlateDecode);
+        file.insert(Name(b"Params"))
+            .dict()
+            .pair(Name(b"Size"), data.len() as i32);
+        file.finish();
+
+        let spec_ref = ctx.alloc.bump();
+        let mut spec = ctx.writer.indirect(spec_ref).dict();
+        spec.pair(Name(b"Type"), Name(b"Filespec"));
+        spec.pair(Name(b"F"), Str(name.as_bytes()));
+        spec.pair(Name(b"UF"), TextStr(name));
+        let mut files = spec.insert(Name(b"EF")).dict();
+        files.pair(Name(b"F"), file_ref);
+        files.pair(Name(b"UF"), file_ref);
+        files.finish();
+        spec.finish();
+
+        result.push((name.clone(), spec_ref));
+    }
+
+    result.sort_by(|(a, _), (b, _)| a.cmp(b));
+    result
+}
+
+/// Write the targets of internal links as destination objects and return the
+/// entries of a name tree.
+#[tracing::instrument(skip_all)]
+fn write_named_destinations(ctx: &mut PdfContext) -> Vec<(EcoString, Ref)> {
+    let mut result = vec![];
+
+    for (index, pos) in ctx.dest_map.items().enumerate() {
+        let page = pos.page.get() - 1;
+        let height = ctx.page_heights[page];
+        let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
+        let dest_ref = ctx.alloc.bump();
+        ctx.writer
+            .indirect(dest_ref)
+            .start::<writers::Destination>()
+            .page(ctx.page_refs[page])
+            .xyz(pos.point.x.to_f32(), height - y.to_f32(), None);
+        result.push((dest_name(index), dest_ref));
+    }
+
+    // Name trees must be sorted by their keys.
+    result.sort_by(|(a, _), (b, _)| a.cmp(b));
+    result
+}
+
+/// The name of the named destination with the given index.
+fn dest_name(index: usize) -> EcoString {
+    eco_format!("dest{index}")
+}
+
 /// Write the page labels.
 #[tracing::instrument(skip_all)]
 fn write_page_labels(ctx: &mut PdfContext) -> Vec<(NonZeroUsize, Ref)> {
@@ -316,3 +451,422 @@ impl RefExt for Ref {
         prev
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::{Destination, Frame, FrameItem, Glyph, Meta, TextItem};
+    use crate::geom::{Color, Point, Size, Smart};
+    use crate::image::{ImageColorSpace, ImageScaling, RasterFormat};
+    use crate::model::{Content, Locator};
+    use crate::syntax::Span;
+
+    #[test]
+    fn test_fixed_date_is_embedded() {
+        let document = Document {
+            pages: vec![Frame::new(Size::splat(Abs::pt(100.0)))],
+            date: Datetime::from_ymd(2023, 8, 15),
+            ..Default::default()
+        };
+
+        let pdf = pdf(&document);
+        let contains = |needle: &[u8]| pdf.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(b"/CreationDate (D:20230815)"));
+        assert!(contains(b"/ModDate (D:20230815)"));
+    }
+
+    #[test]
+    fn test_no_date_is_reproducible() {
+        let document = Document {
+            pages: vec![Frame::new(Size::splat(Abs::pt(100.0)))],
+            date: None,
+            ..Default::default()
+        };
+
+        let first = pdf(&document);
+        assert!(!first.windows(13).any(|w| w == b"/CreationDate"));
+        assert_eq!(first, pdf(&document));
+    }
+
+    #[test]
+    fn test_keywords_are_written_to_info_and_xmp() {
+        let document = Document {
+            pages: vec![Frame::new(Size::splat(Abs::pt(100.0)))],
+            keywords: vec!["typst".into(), "pdf".into()],
+            ..Default::default()
+        };
+
+        let pdf = pdf(&document);
+        let contains = |needle: &[u8]| pdf.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(b"/Keywords (typst, pdf)"));
+        assert!(contains(b"<dc:subject>"));
+        assert!(contains(b"<rdf:li>typst</rdf:li>"));
+        assert!(contains(b"<rdf:li>pdf</rdf:li>"));
+    }
+
+    #[test]
+    fn test_attachments_are_embedded() {
+        let data = b"name,value\nrhino,42";
+        let document = Document {
+            pages: vec![Frame::new(Size::splat(Abs::pt(100.0)))],
+            attachments: vec![("data.csv".into(), data.as_slice().into())],
+            ..Default::default()
+        };
+
+        let pdf = pdf(&document);
+        let contains = |needle: &[u8]| pdf.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(b"/EmbeddedFiles"));
+        assert!(contains(b"/Type /Filespec"));
+        assert!(contains(b"(data.csv)"));
+        assert!(contains(&deflate(data)));
+    }
+
+    #[test]
+    fn test_links_share_named_destination() {
+        let target = Position {
+            page: NonZeroUsize::new(1).unwrap(),
+            point: Point::with_y(Abs::pt(50.0)),
+        };
+
+        let mut frame = Frame::new(Size::splat(Abs::pt(200.0)));
+        for i in 0..10 {
+            frame.push(
+                Point::with_y(Abs::pt(15.0 * i as f64)),
+                FrameItem::Meta(
+                    Meta::Link(Destination::Position(target), None),
+                    Size::splat(Abs::pt(10.0)),
+                ),
+            );
+        }
+
+        let document = Document { pages: vec![frame], ..Default::default() };
+        let pdf = pdf(&document);
+        let count =
+            |needle: &[u8]| pdf.windows(needle.len()).filter(|w| w == &needle).count();
+        assert_eq!(count(b"/Subtype /Link"), 10);
+        assert_eq!(count(b"/D (dest0)"), 10);
+        assert_eq!(count(b"(dest0)"), 11);
+        assert_eq!(count(b"/XYZ"), 1);
+    }
+
+    #[test]
+    fn test_outline_entries_link_to_their_heading() {
+        let mut heading = Content::empty();
+        heading.set_location(Locator::new().locate(1));
+
+        // The outline entry on the first page links to the heading on the
+        // second page, just like the `outline` function's entries do.
+        let mut outline = Frame::new(Size::splat(Abs::pt(100.0)));
+        outline.push(
+            Point::with_y(Abs::pt(10.0)),
+            FrameItem::Meta(
+                Meta::Link(Destination::Location(heading.location().unwrap()), None),
+                Size::new(Abs::pt(80.0), Abs::pt(10.0)),
+            ),
+        );
+
+        let mut body = Frame::new(Size::splat(Abs::pt(100.0)));
+        body.push(
+            Point::with_y(Abs::pt(30.0)),
+            FrameItem::Meta(Meta::Elem(heading), Size::zero()),
+        );
+
+        let document = Document { pages: vec![outline, body], ..Default::default() };
+        let pdf = pdf(&document);
+        let count =
+            |needle: &[u8]| pdf.windows(needle.len()).filter(|w| w == &needle).count();
+        assert_eq!(count(b"/Subtype /Link"), 1);
+        assert_eq!(count(b"/D (dest0)"), 1);
+        assert_eq!(count(b"/XYZ 0 80"), 1);
+    }
+
+    #[test]
+    fn test_adjacent_links_are_merged() {
+        let target = Destination::Url("https://typst.app".into());
+        let mut frame = Frame::new(Size::splat(Abs::pt(200.0)));
+        for i in 0..3 {
+            frame.push(
+                Point::with_x(Abs::pt(20.0 * i as f64)),
+                FrameItem::Meta(
+                    Meta::Link(target.clone(), None),
+                    Size::new(Abs::pt(20.0), Abs::pt(10.0)),
+                ),
+            );
+        }
+
+        let document = Document { pages: vec![frame], ..Default::default() };
+        let pdf = pdf(&document);
+        let count =
+            |needle: &[u8]| pdf.windows(needle.len()).filter(|w| w == &needle).count();
+        assert_eq!(count(b"/Subtype /Link"), 1);
+        assert_eq!(count(b"/Rect [0 200 60 190]"), 1);
+    }
+
+    #[test]
+    fn test_cmyk_text_keeps_device_cmyk() {
+        let data = include_bytes!("../../../../../assets/fonts/IBMPlexSerif-Regular.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let glyph = Glyph {
+            id: font.ttf().glyph_index('A').unwrap().0,
+            x_advance: Em::new(0.5),
+            x_offset: Em::zero(),
+            range: 0..1,
+            span: (Span::detached(), 0),
+        };
+
+        let mut frame = Frame::new(Size::splat(Abs::pt(100.0)));
+        frame.push(
+            Point::with_y(Abs::pt(20.0)),
+            FrameItem::Text(TextItem {
+                font,
+                size: Abs::pt(11.0),
+                fill: Some(Color::from_u8(200, 40, 30, 255).to_cmyk().into()),
+                lang: Lang::ENGLISH,
+                text: "A".into(),
+                glyphs: vec![glyph],
+            }),
+        );
+
+        // The fill is set with the DeviceCMYK operator before the text is
+        // shown.
+        let document = Document { pages: vec![frame], ..Default::default() };
+        let mut ctx = PdfContext::new(&document);
+        page::construct_pages(&mut ctx, &document.pages);
+        let content = &ctx.pages[0].content;
+        let find =
+            |needle: &[u8]| content.windows(needle.len()).position(|w| w == needle);
+        let fill = find(b" k\n").expect("expected a CMYK fill");
+        let show = find(b"TJ").expect("expected shown text");
+        assert!(fill < show);
+        assert_eq!(find(b" rg\n"), None);
+        assert_eq!(find(b" sc\n"), None);
+    }
+
+    #[test]
+    fn test_link_tooltip_becomes_contents() {
+        let mut frame = Frame::new(Size::splat(Abs::pt(200.0)));
+        frame.push(
+            Point::zero(),
+            FrameItem::Meta(
+                Meta::Link(
+                    Destination::Url("https://typst.app".into()),
+                    Some("Typst's website".into()),
+                ),
+                Size::splat(Abs::pt(10.0)),
+            ),
+        );
+
+        let document = Document { pages: vec![frame], ..Default::default() };
+        let pdf = pdf(&document);
+        let contains = |needle: &[u8]| pdf.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(b"/Contents (Typst's website)"));
+    }
+
+    #[test]
+    fn test_export_is_deterministic() {
+        let data = include_bytes!("../../../../../assets/fonts/IBMPlexSerif-Regular.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let glyphs = "Hi"
+            .char_indices()
+            .map(|(i, c)| Glyph {
+                id: font.ttf().glyph_index(c).unwrap().0,
+                x_advance: Em::new(0.5),
+                x_offset: Em::zero(),
+                range: i as u16..i as u16 + 1,
+                span: (Span::detached(), 0),
+            })
+            .collect();
+
+        let mut frame = Frame::new(Size::splat(Abs::pt(100.0)));
+        frame.push(
+            Point::with_y(Abs::pt(20.0)),
+            FrameItem::Text(TextItem {
+                font,
+                size: Abs::pt(11.0),
+                fill: Some(Color::BLACK.into()),
+                lang: Lang::ENGLISH,
+                text: "Hi".into(),
+                glyphs,
+            }),
+        );
+
+        let make = || Document {
+            pages: vec![frame.clone(), frame.clone()],
+            title: Some("Reproducible".into()),
+            date: Datetime::from_ymd(2023, 8, 15),
+            attachments: vec![("data.csv".into(), b"a,b".as_slice().into())],
+            ..Default::default()
+        };
+
+        assert_eq!(pdf(&make()), pdf(&make()));
+    }
+
+    #[test]
+    fn test_translucent_text_sets_fill_alpha() {
+        let data = include_bytes!("../../../../../assets/fonts/IBMPlexSerif-Regular.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let glyph = Glyph {
+            id: font.ttf().glyph_index('A').unwrap().0,
+            x_advance: Em::new(0.5),
+            x_offset: Em::zero(),
+            range: 0..1,
+            span: (Span::detached(), 0),
+        };
+
+        let mut frame = Frame::new(Size::splat(Abs::pt(100.0)));
+        frame.push(
+            Point::with_y(Abs::pt(20.0)),
+            FrameItem::Text(TextItem {
+                font,
+                size: Abs::pt(11.0),
+                fill: Some(Color::from_u8(0, 0, 0, 128).into()),
+                lang: Lang::ENGLISH,
+                text: "A".into(),
+                glyphs: vec![glyph],
+            }),
+        );
+
+        let document = Document { pages: vec![frame], ..Default::default() };
+        let pdf = pdf(&document);
+        let contains = |needle: &[u8]| pdf.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(b"/ca 0.50"));
+    }
+
+    #[test]
+    fn test_invisible_text_uses_invisible_render_mode() {
+        let data = include_bytes!("../../../../../assets/fonts/IBMPlexSerif-Regular.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let glyph = Glyph {
+            id: font.ttf().glyph_index('A').unwrap().0,
+            x_advance: Em::new(0.5),
+            x_offset: Em::zero(),
+            range: 0..1,
+            span: (Span::detached(), 0),
+        };
+
+        let mut frame = Frame::new(Size::splat(Abs::pt(100.0)));
+        frame.push(
+            Point::with_y(Abs::pt(20.0)),
+            FrameItem::Text(TextItem {
+                font,
+                size: Abs::pt(11.0),
+                fill: None,
+                lang: Lang::ENGLISH,
+                text: "A".into(),
+                glyphs: vec![glyph],
+            }),
+        );
+
+        // The text is still shown, but with the invisible rendering mode,
+        // which is reset afterwards.
+        let document = Document { pages: vec![frame], ..Default::default() };
+        let mut ctx = PdfContext::new(&document);
+        page::construct_pages(&mut ctx, &document.pages);
+        let content = &ctx.pages[0].content;
+        let find =
+            |needle: &[u8]| content.windows(needle.len()).position(|w| w == needle);
+        let invisible = find(b"3 Tr\n").expect("expected the invisible mode");
+        let show = find(b"TJ").expect("expected shown text");
+        let reset = find(b"0 Tr\n").expect("expected the mode to be reset");
+        assert!(invisible < show && show < reset);
+        assert_eq!(find(b" rg\n"), None);
+    }
+
+    #[test]
+    fn test_roman_front_matter_page_labels() {
+        let page = |style, offset| {
+            let mut frame = Frame::new(Size::splat(Abs::pt(100.0)));
+            frame.push_positionless_meta(Meta::PdfPageLabel(PdfPageLabel {
+                prefix: None,
+                style: Some(style),
+                offset: NonZeroUsize::new(offset),
+            }));
+            frame
+        };
+
+        let document = Document {
+            pages: vec![
+                page(PdfPageLabelStyle::LowerRoman, 1),
+                page(PdfPageLabelStyle::LowerRoman, 2),
+                page(PdfPageLabelStyle::Arabic, 1),
+                page(PdfPageLabelStyle::Arabic, 2),
+            ],
+            ..Default::default()
+        };
+
+        let pdf = pdf(&document);
+        let text = String::from_utf8_lossy(&pdf);
+        assert_eq!(text.matches("/S /r").count(), 1);
+        assert_eq!(text.matches("/S /D").count(), 1);
+
+        // One label range starting at the first page and one at the third.
+        let start = text.find("/Nums [").unwrap() + "/Nums [".len();
+        let end = start + text[start..].find(']').unwrap();
+        let nums: Vec<&str> = text[start..end].split_whitespace().collect();
+        assert_eq!(nums.len(), 8);
+        assert_eq!(nums[0], "0");
+        assert_eq!(nums[4], "2");
+    }
+
+    #[test]
+    fn test_image_scaling_sets_interpolate_flag() {
+        let export = |scaling| {
+            let data = include_bytes!("../../../../../assets/files/rhino.png");
+            let image = Image::new(
+                data.as_slice().into(),
+                RasterFormat::Png.into(),
+                None,
+                scaling,
+                true,
+                Smart::Auto,
+            )
+            .unwrap();
+            let size = Size::splat(Abs::pt(50.0));
+            let mut frame = Frame::new(size);
+            frame.push(Point::zero(), FrameItem::Image(image, size, Span::detached()));
+            let document = Document { pages: vec![frame], ..Default::default() };
+            String::from_utf8_lossy(&pdf(&document)).into_owned()
+        };
+
+        let pixelated = export(ImageScaling::Pixelated);
+        assert!(pixelated.contains("/Interpolate false"));
+        assert!(!pixelated.contains("/Interpolate true"));
+
+        let smooth = export(ImageScaling::Smooth);
+        assert!(smooth.contains("/Interpolate true"));
+        assert!(!smooth.contains("/Interpolate false"));
+    }
+
+    #[test]
+    fn test_images_in_different_color_spaces_are_kept_apart() {
+        let export = |spaces: [Smart<ImageColorSpace>; 2]| {
+            let data = include_bytes!("../../../../../assets/files/rhino.png");
+            let size = Size::splat(Abs::pt(50.0));
+            let mut frame = Frame::new(Size::new(size.x * 2.0, size.y));
+            for (i, space) in spaces.into_iter().enumerate() {
+                let image = Image::new(
+                    data.as_slice().into(),
+                    RasterFormat::Png.into(),
+                    None,
+                    ImageScaling::Smooth,
+                    true,
+                    space,
+                )
+                .unwrap();
+                let pos = Point::with_x(size.x * i as f64);
+                frame.push(pos, FrameItem::Image(image, size, Span::detached()));
+            }
+            let document = Document { pages: vec![frame], ..Default::default() };
+            String::from_utf8_lossy(&pdf(&document))
+                .matches("/Subtype /Image")
+                .count()
+        };
+
+        // The same image twice is written once, but a grayscale copy needs its
+        // own pixels.
+        let same = export([Smart::Auto, Smart::Auto]);
+        let mixed = export([Smart::Auto, Smart::Custom(ImageColorSpace::Gray)]);
+        assert!(same > 0);
+        assert!(mixed > same);
+    }
+}
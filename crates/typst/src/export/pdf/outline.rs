@@ -86,17 +86,19 @@ pub fn write_outline(ctx: &mut PdfContext) -> Option<Ref> {
     let root_id = ctx.alloc.bump();
     let start_ref = ctx.alloc;
     let len = tree.len();
+    let collapse = ctx.document.outline_collapse;
 
     let mut prev_ref = None;
     for (i, node) in tree.iter().enumerate() {
-        prev_ref = Some(write_outline_item(ctx, node, root_id, prev_ref, i + 1 == len));
+        prev_ref =
+            Some(write_outline_item(ctx, node, 1, root_id, prev_ref, i + 1 == len));
     }
 
     ctx.writer
         .outline(root_id)
         .first(start_ref)
         .last(Ref::new(ctx.alloc.get() - 1))
-        .count(tree.len() as i32);
+        .count(visible(&tree, 1, collapse) as i32);
 
     Some(root_id)
 }
@@ -126,6 +128,41 @@ impl HeadingNode {
     fn len(&self) -> usize {
         1 + self.children.iter().map(Self::len).sum::<usize>()
     }
+
+    /// The value of the node's `/Count` entry when it sits at the given depth.
+    ///
+    /// The magnitude is the number of descendants visible when the node is
+    /// expanded. The sign is positive if the node is expanded by default and
+    /// negative if it is collapsed.
+    fn count(&self, depth: usize, collapse: Option<NonZeroUsize>) -> i32 {
+        let visible = visible(&self.children, depth + 1, collapse) as i32;
+        if expanded(depth, collapse) {
+            visible
+        } else {
+            -visible
+        }
+    }
+}
+
+/// Whether nodes at the given depth are expanded by default.
+fn expanded(depth: usize, collapse: Option<NonZeroUsize>) -> bool {
+    collapse.map_or(true, |collapse| depth < collapse.get())
+}
+
+/// The number of visible items among sibling nodes at the given depth and
+/// their descendants.
+fn visible(nodes: &[HeadingNode], depth: usize, collapse: Option<NonZeroUsize>) -> usize {
+    nodes
+        .iter()
+        .map(|node| {
+            let inner = if expanded(depth, collapse) {
+                visible(&node.children, depth + 1, collapse)
+            } else {
+                0
+            };
+            1 + inner
+        })
+        .sum()
 }
 
 /// Write an outline item and all its children.
@@ -133,6 +170,7 @@ impl HeadingNode {
 fn write_outline_item(
     ctx: &mut PdfContext,
     node: &HeadingNode,
+    depth: usize,
     parent_ref: Ref,
     prev_ref: Option<Ref>,
     is_last: bool,
@@ -155,7 +193,7 @@ fn write_outline_item(
         let current_child = Ref::new(id.get() + 1);
         outline.first(current_child);
         outline.last(Ref::new(next_ref.get() - 1));
-        outline.count(-(node.children.len() as i32));
+        outline.count(node.count(depth, ctx.document.outline_collapse));
     }
 
     let body = node.element.expect_field::<Content>("body");
@@ -180,6 +218,7 @@ fn write_outline_item(
         prev_ref = Some(write_outline_item(
             ctx,
             child,
+            depth + 1,
             id,
             prev_ref,
             i + 1 == node.children.len(),
@@ -188,3 +227,44 @@ fn write_outline_item(
 
     id
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(level: usize, children: Vec<HeadingNode>) -> HeadingNode {
+        HeadingNode {
+            element: Content::empty(),
+            level: NonZeroUsize::new(level).unwrap(),
+            bookmarked: true,
+            children,
+        }
+    }
+
+    #[test]
+    fn test_outline_collapses_deeper_levels() {
+        let tree = vec![
+            node(1, vec![node(2, vec![node(3, vec![])]), node(2, vec![])]),
+            node(1, vec![node(2, vec![])]),
+        ];
+
+        // Top-level items are expanded, so their children are visible, while
+        // second-level items are collapsed and hide their children.
+        let collapse = NonZeroUsize::new(2);
+        assert_eq!(visible(&tree, 1, collapse), 5);
+        assert_eq!(tree[0].count(1, collapse), 2);
+        assert_eq!(tree[1].count(1, collapse), 1);
+        assert_eq!(tree[0].children[0].count(2, collapse), -1);
+
+        // By default, everything is collapsed.
+        let collapse = Some(NonZeroUsize::ONE);
+        assert_eq!(visible(&tree, 1, collapse), 2);
+        assert_eq!(tree[0].count(1, collapse), -2);
+        assert_eq!(tree[0].children[0].count(2, collapse), -1);
+
+        // Without a limit, everything is expanded.
+        assert_eq!(visible(&tree, 1, None), 6);
+        assert_eq!(tree[0].count(1, None), 3);
+        assert_eq!(tree[0].children[0].count(2, None), 1);
+    }
+}
@@ -3,18 +3,18 @@ use std::num::NonZeroUsize;
 use ecow::{eco_format, EcoString};
 use pdf_writer::types::{
     ActionType, AnnotationType, ColorSpaceOperand, LineCapStyle, LineJoinStyle,
-    NumberingStyle,
+    NumberingStyle, TextRenderingMode,
 };
-use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str};
+use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str, TextStr};
 
 use super::color::PaintEncode;
 use super::extg::ExternalGraphicsState;
-use super::{deflate, AbsExt, EmExt, PdfContext, RefExt};
+use super::{deflate, dest_name, AbsExt, EmExt, PdfContext, RefExt};
 use crate::doc::{Destination, Frame, FrameItem, GroupItem, Meta, TextItem};
 use crate::font::Font;
 use crate::geom::{
-    self, Abs, Em, FixedStroke, Geometry, LineCap, LineJoin, Numeric, Paint, Point,
-    Ratio, Shape, Size, Transform,
+    self, Abs, Em, FillRule, FixedStroke, Geometry, LineCap, LineJoin, Numeric, Paint,
+    Point, Ratio, Shape, Size, Transform,
 };
 use crate::image::Image;
 
@@ -144,10 +144,13 @@ fn write_page(ctx: &mut PdfContext, i: usize) {
     }
 
     let mut annotations = page_writer.annotations();
-    for (dest, rect) in &page.links {
+    for (dest, tooltip, rect) in &page.links {
         let mut annotation = annotations.push();
         annotation.subtype(AnnotationType::Link).rect(*rect);
         annotation.border(0.0, 0.0, 0.0, None);
+        if let Some(tooltip) = tooltip {
+            annotation.contents(TextStr(tooltip));
+        }
 
         let pos = match dest {
             Destination::Url(uri) => {
@@ -161,15 +164,15 @@ fn write_page(ctx: &mut PdfContext, i: usize) {
             Destination::Location(loc) => ctx.introspector.position(*loc),
         };
 
-        let index = pos.page.get() - 1;
-        let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
-        if let Some(&height) = ctx.page_heights.get(index) {
+        // Internal links refer to a named destination, which is shared by
+        // all links to the same position.
+        if pos.page.get() <= ctx.page_heights.len() {
+            ctx.dest_map.insert(pos);
+            let name = dest_name(ctx.dest_map.map(&pos));
             annotation
                 .action()
                 .action_type(ActionType::GoTo)
-                .destination()
-                .page(ctx.page_refs[index])
-                .xyz(pos.point.x.to_f32(), height - y.to_f32(), None);
+                .pair(Name(b"D"), Str(name.as_bytes()));
         }
     }
 
@@ -190,8 +193,8 @@ pub struct Page {
     pub content: Vec<u8>,
     /// Whether the page uses opacities.
     pub uses_opacities: bool,
-    /// Links in the PDF coordinate system.
-    pub links: Vec<(Destination, Rect)>,
+    /// Links and their tooltips in the PDF coordinate system.
+    pub links: Vec<(Destination, Option<EcoString>, Rect)>,
     /// The page's PDF label.
     pub label: Option<PdfPageLabel>,
 }
@@ -206,7 +209,7 @@ pub struct PageContext<'a, 'b> {
     saves: Vec<State>,
     bottom: f32,
     uses_opacities: bool,
-    links: Vec<(Destination, Rect)>,
+    links: Vec<(Destination, Option<EcoString>, Rect)>,
 }
 
 /// A simulated graphics state used to deduplicate graphics state changes and
@@ -365,7 +368,7 @@ fn write_frame(ctx: &mut PageContext, frame: &Frame) {
             FrameItem::Shape(shape, _) => write_shape(ctx, x, y, shape),
             FrameItem::Image(image, size, _) => write_image(ctx, x, y, image, *size),
             FrameItem::Meta(meta, size) => match meta {
-                Meta::Link(dest) => write_link(ctx, pos, dest, *size),
+                Meta::Link(dest, tooltip) => write_link(ctx, pos, dest, tooltip, *size),
                 Meta::Elem(_) => {}
                 Meta::Hide => {}
                 Meta::PageNumbering(_) => {}
@@ -408,11 +411,19 @@ fn write_text(ctx: &mut PageContext, x: f32, y: f32, text: &TextItem) {
         glyph_set.entry(g.id).or_insert_with(|| segment.into());
     }
 
-    ctx.set_fill(&text.fill);
+    if let Some(fill) = &text.fill {
+        ctx.set_fill(fill);
+    }
     ctx.set_font(&text.font, text.size);
-    ctx.set_opacities(None, Some(&text.fill));
+    ctx.set_opacities(None, text.fill.as_ref());
     ctx.content.begin_text();
 
+    // Invisible text is still written, so that it can be selected and
+    // extracted.
+    if text.fill.is_none() {
+        ctx.content.set_text_rendering_mode(TextRenderingMode::Invisible);
+    }
+
     // Positiosn the text.
     ctx.content.set_text_matrix([1.0, 0.0, 0.0, -1.0, x, y]);
 
@@ -452,6 +463,12 @@ fn write_text(ctx: &mut PageContext, x: f32, y: f32, text: &TextItem) {
 
     items.finish();
     positioned.finish();
+
+    // The rendering mode is part of the graphics state, so we need to reset it.
+    if text.fill.is_none() {
+        ctx.content.set_text_rendering_mode(TextRenderingMode::Fill);
+    }
+
     ctx.content.end_text();
 }
 
@@ -498,11 +515,13 @@ fn write_shape(ctx: &mut PageContext, x: f32, y: f32, shape: &Shape) {
         }
     }
 
-    match (&shape.fill, stroke) {
-        (None, None) => unreachable!(),
-        (Some(_), None) => ctx.content.fill_nonzero(),
-        (None, Some(_)) => ctx.content.stroke(),
-        (Some(_), Some(_)) => ctx.content.fill_nonzero_and_stroke(),
+    match (&shape.fill, shape.fill_rule, stroke) {
+        (None, _, None) => unreachable!(),
+        (Some(_), FillRule::NonZero, None) => ctx.content.fill_nonzero(),
+        (Some(_), FillRule::EvenOdd, None) => ctx.content.fill_even_odd(),
+        (None, _, Some(_)) => ctx.content.stroke(),
+        (Some(_), FillRule::NonZero, Some(_)) => ctx.content.fill_nonzero_and_stroke(),
+        (Some(_), FillRule::EvenOdd, Some(_)) => ctx.content.fill_even_odd_and_stroke(),
     };
 }
 
@@ -556,7 +575,13 @@ fn write_image(ctx: &mut PageContext, x: f32, y: f32, image: &Image, size: Size)
 }
 
 /// Save a link for later writing in the annotations dictionary.
-fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size) {
+fn write_link(
+    ctx: &mut PageContext,
+    pos: Point,
+    dest: &Destination,
+    tooltip: &Option<EcoString>,
+    size: Size,
+) {
     let mut min_x = Abs::inf();
     let mut min_y = Abs::inf();
     let mut max_x = -Abs::inf();
@@ -582,7 +607,24 @@ fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size)
     let y2 = min_y.to_f32();
     let rect = Rect::new(x1, y1, x2, y2);
 
-    ctx.links.push((dest.clone(), rect));
+    // Merge the rectangle into the previous one if both link to the same
+    // destination and touch on the same line, as happens for a link whose
+    // text is split into multiple runs.
+    if let Some((prev_dest, prev_tooltip, prev)) = ctx.links.last_mut() {
+        const EPS: f32 = 0.01;
+        if prev_dest == dest
+            && prev_tooltip == tooltip
+            && (prev.y1 - rect.y1).abs() < EPS
+            && (prev.y2 - rect.y2).abs() < EPS
+            && rect.x1 >= prev.x1 - EPS
+            && rect.x1 <= prev.x2 + EPS
+        {
+            prev.x2 = prev.x2.max(rect.x2);
+            return;
+        }
+    }
+
+    ctx.links.push((dest.clone(), tooltip.clone(), rect));
 }
 
 impl From<&LineCap> for LineCapStyle {
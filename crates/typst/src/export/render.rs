@@ -14,10 +14,10 @@ use usvg::{NodeExt, TreeParsing};
 use crate::doc::{Frame, FrameItem, GroupItem, Meta, TextItem};
 use crate::font::Font;
 use crate::geom::{
-    self, Abs, Color, FixedStroke, Geometry, LineCap, LineJoin, Paint, PathItem, Shape,
-    Size, Transform,
+    self, Abs, Color, FillRule, FixedStroke, Geometry, LineCap, LineJoin, Paint,
+    PathItem, Shape, Size, Smart, Transform,
 };
-use crate::image::{Image, ImageKind, RasterFormat};
+use crate::image::{Image, ImageKind, ImageScaling, RasterFormat};
 
 /// Export a frame into a raster image.
 ///
@@ -104,7 +104,7 @@ fn render_frame(
                 render_image(canvas, ts, mask, image, *size);
             }
             FrameItem::Meta(meta, _) => match meta {
-                Meta::Link(_) => {}
+                Meta::Link(..) => {}
                 Meta::Elem(_) => {}
                 Meta::PageNumbering(_) => {}
                 Meta::PdfPageLabel(_) => {}
@@ -174,6 +174,11 @@ fn render_text(
     mask: Option<&sk::Mask>,
     text: &TextItem,
 ) {
+    // Invisible text is laid out, but not drawn.
+    if text.fill.is_none() {
+        return;
+    }
+
     let mut x = 0.0;
     for glyph in &text.glyphs {
         let id = GlyphId(glyph.id);
@@ -289,7 +294,15 @@ fn render_bitmap_glyph(
     if raster.format != ttf_parser::RasterImageFormat::PNG {
         return None;
     }
-    let image = Image::new(raster.data.into(), RasterFormat::Png.into(), None).ok()?;
+    let image = Image::new(
+        raster.data.into(),
+        RasterFormat::Png.into(),
+        None,
+        ImageScaling::default(),
+        false,
+        Smart::Auto,
+    )
+    .ok()?;
 
     // FIXME: Vertical alignment isn't quite right for Apple Color Emoji,
     // and maybe also for Noto Color Emoji. And: Is the size calculation
@@ -310,6 +323,7 @@ fn render_outline_glyph(
     text: &TextItem,
     id: GlyphId,
 ) -> Option<()> {
+    let fill = text.fill.as_ref()?;
     let ppem = text.size.to_f32() * ts.sy;
 
     // Render a glyph directly as a path. This only happens when the fast glyph
@@ -322,7 +336,7 @@ fn render_outline_glyph(
             builder.0.finish()?
         };
 
-        let paint = (&text.fill).into();
+        let paint = fill.into();
         let rule = sk::FillRule::default();
 
         // Flip vertically because font design coordinate
@@ -361,7 +375,7 @@ fn render_outline_glyph(
         let mw = bitmap.width;
         let mh = bitmap.height;
 
-        let Paint::Solid(color) = text.fill;
+        let &Paint::Solid(color) = fill;
         let color = sk::ColorU8::from(color);
 
         // Pad the pixmap with 1 pixel in each dimension so that we do
@@ -406,7 +420,7 @@ fn render_outline_glyph(
         let bottom = top + mh;
 
         // Premultiply the text color.
-        let Paint::Solid(color) = text.fill;
+        let &Paint::Solid(color) = fill;
         let color = bytemuck::cast(sk::ColorU8::from(color).premultiply());
 
         // Blend the glyph bitmap with the existing pixels on the canvas.
@@ -462,7 +476,7 @@ fn render_shape(
             paint.anti_alias = false;
         }
 
-        let rule = sk::FillRule::default();
+        let rule = shape.fill_rule.into();
         canvas.fill_path(&path, &paint, rule, ts, mask);
     }
 
@@ -588,8 +602,13 @@ fn scaled_texture(image: &Image, w: u32, h: u32) -> Option<Arc<sk::Pixmap>> {
     match image.kind() {
         ImageKind::Raster(raster) => {
             let downscale = w < image.width();
-            let filter =
-                if downscale { FilterType::Lanczos3 } else { FilterType::CatmullRom };
+            let filter = if image.scaling() == ImageScaling::Pixelated {
+                FilterType::Nearest
+            } else if downscale {
+                FilterType::Lanczos3
+            } else {
+                FilterType::CatmullRom
+            };
             let buf = raster.dynamic().resize(w, h, filter);
             for ((_, _, src), dest) in buf.pixels().zip(pixmap.pixels_mut()) {
                 let Rgba([r, g, b, a]) = src;
@@ -643,6 +662,15 @@ impl From<Color> for sk::Color {
     }
 }
 
+impl From<FillRule> for sk::FillRule {
+    fn from(fill_rule: FillRule) -> Self {
+        match fill_rule {
+            FillRule::NonZero => sk::FillRule::Winding,
+            FillRule::EvenOdd => sk::FillRule::EvenOdd,
+        }
+    }
+}
+
 impl From<&LineCap> for sk::LineCap {
     fn from(line_cap: &LineCap) -> Self {
         match line_cap {
@@ -723,3 +751,104 @@ fn alpha_mul(color: u32, scale: u32) -> u32 {
     let ag = ((color >> 8) & mask) * scale;
     (rb & mask) | (ag & !mask)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::geom::Point;
+    use crate::syntax::Span;
+
+    /// Create a two-pixel image that is black on the left and white on the
+    /// right.
+    fn two_pixels(scaling: ImageScaling) -> Image {
+        let pixels = vec![0, 0, 0, 255, 255, 255, 255, 255];
+        let buf = image::RgbaImage::from_raw(2, 1, pixels).unwrap();
+        let mut data = Cursor::new(vec![]);
+        image::DynamicImage::ImageRgba8(buf)
+            .write_to(&mut data, image::ImageFormat::Png)
+            .unwrap();
+        Image::new(
+            data.into_inner().into(),
+            RasterFormat::Png.into(),
+            None,
+            scaling,
+            true,
+            Smart::Auto,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_pixelated_scaling_keeps_hard_edges() {
+        let pixmap = scaled_texture(&two_pixels(ImageScaling::Pixelated), 8, 4).unwrap();
+        assert!(pixmap.pixels().iter().all(|p| p.red() == 0 || p.red() == 255));
+    }
+
+    #[test]
+    fn test_smooth_scaling_blends() {
+        let pixmap = scaled_texture(&two_pixels(ImageScaling::Smooth), 8, 4).unwrap();
+        assert!(pixmap.pixels().iter().any(|p| p.red() != 0 && p.red() != 255));
+    }
+
+    #[test]
+    fn test_transparent_background_is_kept() {
+        let frame = Frame::new(Size::splat(Abs::pt(4.0)));
+        let pixmap = render(&frame, 1.0, Color::from_u8(0, 0, 0, 0));
+        assert!(pixmap.pixels().iter().all(|p| p.alpha() == 0));
+    }
+
+    #[test]
+    fn test_page_fill_covers_background() {
+        let mut frame = Frame::new(Size::splat(Abs::pt(4.0)));
+        frame.fill(Color::BLACK.into());
+        let pixmap = render(&frame, 1.0, Color::from_u8(0, 0, 0, 0));
+        assert!(pixmap.pixels().iter().all(|p| p.red() == 0 && p.alpha() == 255));
+    }
+
+    #[test]
+    fn test_fill_rule_decides_star_center() {
+        let render_star = |fill_rule| {
+            let mut path = geom::Path::new();
+            let point = |x, y| Point::new(Abs::pt(x), Abs::pt(y));
+            path.move_to(point(15.0, 0.0));
+            path.line_to(point(24.0, 30.0));
+            path.line_to(point(0.0, 11.0));
+            path.line_to(point(30.0, 11.0));
+            path.line_to(point(6.0, 30.0));
+            path.close_path();
+
+            let mut frame = Frame::new(Size::splat(Abs::pt(30.0)));
+            let shape = Shape {
+                geometry: Geometry::Path(path),
+                fill: Some(Color::BLACK.into()),
+                fill_rule,
+                stroke: None,
+            };
+            frame.push(Point::zero(), FrameItem::Shape(shape, Span::detached()));
+            render(&frame, 1.0, Color::WHITE)
+        };
+
+        // The center of the star is enclosed twice, so the even-odd rule leaves
+        // it hollow while the non-zero rule fills it. A point of the star is
+        // filled either way.
+        let solid = render_star(FillRule::NonZero);
+        let hollow = render_star(FillRule::EvenOdd);
+        assert_eq!(solid.pixel(15, 16).unwrap().red(), 0);
+        assert_eq!(hollow.pixel(15, 16).unwrap().red(), 255);
+        assert_eq!(hollow.pixel(15, 5).unwrap().red(), 0);
+    }
+
+    #[test]
+    fn test_translucent_fill_blends_with_background() {
+        let size = Size::splat(Abs::pt(4.0));
+        let shape = Geometry::Rect(size).filled(Color::from_u8(0, 0, 0, 128).into());
+        let mut frame = Frame::new(size);
+        frame.push(Point::zero(), FrameItem::Shape(shape, Span::detached()));
+
+        // Half-transparent black over white comes out as mid gray.
+        let pixmap = render(&frame, 1.0, Color::WHITE);
+        assert!(pixmap.pixels().iter().all(|p| p.red().abs_diff(128) <= 1));
+    }
+}
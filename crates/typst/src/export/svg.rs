@@ -10,10 +10,10 @@ use xmlwriter::XmlWriter;
 use crate::doc::{Frame, FrameItem, GroupItem, TextItem};
 use crate::font::Font;
 use crate::geom::{
-    Abs, Angle, Axes, Color, FixedStroke, Geometry, LineCap, LineJoin, Paint, PathItem,
-    Ratio, Shape, Size, Transform,
+    Abs, Angle, Axes, Color, FillRule, FixedStroke, Geometry, LineCap, LineJoin, Paint,
+    PathItem, Ratio, Shape, Size, Smart, Transform,
 };
-use crate::image::{Image, ImageFormat, RasterFormat, VectorFormat};
+use crate::image::{Image, ImageFormat, ImageScaling, RasterFormat, VectorFormat};
 use crate::util::hash128;
 
 /// Export a frame into a SVG file.
@@ -154,6 +154,11 @@ impl SVGRenderer {
     /// try to render the text as SVG first, then bitmap, then outline. If none
     /// of them works, we will skip the text.
     fn render_text(&mut self, text: &TextItem) {
+        // Invisible text is laid out, but not drawn.
+        if text.fill.is_none() {
+            return;
+        }
+
         let scale: f64 = text.size.to_pt() / text.font.units_per_em();
         let inv_scale: f64 = text.font.units_per_em() / text.size.to_pt();
 
@@ -177,6 +182,32 @@ impl SVGRenderer {
         }
 
         self.xml.end_element();
+        self.render_text_layer(text);
+    }
+
+    /// Render the plain text of a text item as invisible SVG text on top of
+    /// its glyphs, so that it can be selected and copied. Each text item is
+    /// one run of a single color and becomes one `tspan` with that fill.
+    fn render_text_layer(&mut self, text: &TextItem) {
+        let Some(fill) = &text.fill else { return };
+        if text.text.is_empty() {
+            return;
+        }
+
+        // Indentation inside the text element would become part of the text.
+        self.xml.set_preserve_whitespaces(true);
+        self.xml.start_element("text");
+        self.xml.write_attribute("class", "typst-text-layer");
+        self.xml.write_attribute("xml:space", "preserve");
+        self.xml.write_attribute("font-size", &text.size.to_pt());
+        self.xml.write_attribute("textLength", &text.width().to_pt());
+        self.xml.write_attribute("fill-opacity", "0");
+        self.xml.start_element("tspan");
+        self.write_fill(fill);
+        self.xml.write_text(&text.text);
+        self.xml.end_element();
+        self.xml.end_element();
+        self.xml.set_preserve_whitespaces(false);
     }
 
     /// Render a glyph defined by an SVG.
@@ -262,6 +293,7 @@ impl SVGRenderer {
         x_offset: f64,
         inv_scale: f64,
     ) -> Option<()> {
+        let fill = text.fill.as_ref()?;
         let path = convert_outline_glyph_to_path(&text.font, id)?;
         let hash = hash128(&(&text.font, id));
         let id = self.glyphs.insert_with(hash, || RenderedGlyph::Path(path));
@@ -270,7 +302,7 @@ impl SVGRenderer {
         self.xml.write_attribute_fmt("xlink:href", format_args!("#{id}"));
         self.xml
             .write_attribute_fmt("x", format_args!("{}", x_offset * inv_scale));
-        self.write_fill(&text.fill);
+        self.write_fill(fill);
         self.xml.end_element();
 
         Some(())
@@ -283,6 +315,9 @@ impl SVGRenderer {
 
         if let Some(paint) = &shape.fill {
             self.write_fill(paint);
+            if shape.fill_rule == FillRule::EvenOdd {
+                self.xml.write_attribute("fill-rule", "evenodd");
+            }
         } else {
             self.xml.write_attribute("fill", "none");
         }
@@ -347,6 +382,9 @@ impl SVGRenderer {
         self.xml.write_attribute("width", &size.x.to_pt());
         self.xml.write_attribute("height", &size.y.to_pt());
         self.xml.write_attribute("preserveAspectRatio", "none");
+        if image.scaling() == ImageScaling::Pixelated {
+            self.xml.write_attribute("style", "image-rendering: pixelated");
+        }
         self.xml.end_element();
     }
 
@@ -425,7 +463,15 @@ fn convert_bitmap_glyph_to_image(font: &Font, id: GlyphId) -> Option<(Image, f64
     if raster.format != ttf_parser::RasterImageFormat::PNG {
         return None;
     }
-    let image = Image::new(raster.data.into(), RasterFormat::Png.into(), None).ok()?;
+    let image = Image::new(
+        raster.data.into(),
+        RasterFormat::Png.into(),
+        None,
+        ImageScaling::default(),
+        false,
+        Smart::Auto,
+    )
+    .ok()?;
     Some((image, raster.x as f64, raster.y as f64))
 }
 
@@ -726,3 +772,56 @@ impl ColorEncode for Color {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::{Glyph, Lang};
+    use crate::geom::{Em, Point};
+    use crate::syntax::Span;
+
+    /// Create a text item with one glyph per character.
+    fn text_item(font: &Font, text: &str, fill: Color) -> TextItem {
+        let glyphs = text
+            .char_indices()
+            .map(|(i, c)| Glyph {
+                id: font.ttf().glyph_index(c).map_or(0, |id| id.0),
+                x_advance: Em::new(0.5),
+                x_offset: Em::zero(),
+                range: i as u16..i as u16 + 1,
+                span: (Span::detached(), 0),
+            })
+            .collect();
+        TextItem {
+            font: font.clone(),
+            size: Abs::pt(10.0),
+            fill: Some(fill.into()),
+            lang: Lang::ENGLISH,
+            text: text.into(),
+            glyphs,
+        }
+    }
+
+    #[test]
+    fn test_text_runs_become_tspans() {
+        let data = include_bytes!("../../../../assets/fonts/DejaVuSansMono.ttf");
+        let font = Font::new(data.as_slice().into(), 0).unwrap();
+        let keyword = Color::from_u8(0xd7, 0x3a, 0x49, 255);
+        let ident = Color::from_u8(0x4b, 0x69, 0xc6, 255);
+
+        let mut frame = Frame::new(Size::new(Abs::pt(100.0), Abs::pt(20.0)));
+        frame.push(
+            Point::with_y(Abs::pt(10.0)),
+            FrameItem::Text(text_item(&font, "    let", keyword)),
+        );
+        frame.push(
+            Point::new(Abs::pt(40.0), Abs::pt(10.0)),
+            FrameItem::Text(text_item(&font, " x", ident)),
+        );
+
+        let svg = svg(&frame);
+        assert!(svg.contains("xml:space=\"preserve\""));
+        assert!(svg.contains("<tspan fill=\"#d73a49\">    let</tspan>"));
+        assert!(svg.contains("<tspan fill=\"#4b69c6\"> x</tspan>"));
+    }
+}
@@ -6,6 +6,7 @@ use ttf_parser::{name_id, PlatformId, Tag};
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::{Font, FontStretch, FontStyle, FontVariant, FontWeight};
+use crate::doc::{Lang, Region};
 
 /// Metadata about a collection of fonts.
 #[derive(Debug, Default, Clone, Hash)]
@@ -82,15 +83,52 @@ impl FontBook {
             .copied()
     }
 
+    /// Determine which characters of the `text` the fonts of the given
+    /// `families` can render, trying the families in order. Returns the
+    /// covered and the missing characters, each listed once in the order of
+    /// their first occurrence.
+    ///
+    /// The `families` should be all lowercase.
+    pub fn coverage<'a>(
+        &self,
+        families: impl IntoIterator<Item = &'a str>,
+        text: &str,
+    ) -> (Vec<char>, Vec<char>) {
+        let ids: Vec<usize> = families
+            .into_iter()
+            .flat_map(|family| self.select_family(family))
+            .collect();
+
+        let mut covered = vec![];
+        let mut missing = vec![];
+        for c in text.chars() {
+            if covered.contains(&c) || missing.contains(&c) {
+                continue;
+            }
+
+            if ids.iter().any(|&id| self.infos[id].coverage.contains(c as u32)) {
+                covered.push(c);
+            } else {
+                missing.push(c);
+            }
+        }
+
+        (covered, missing)
+    }
+
     /// Try to find and load a fallback font that
+    /// - is suitable for shaping the given `text`
+    /// - is designed for the given `lang` and `region` (if any), which matters
+    ///   for scripts like Han whose glyphs differ between regions
     /// - is as close as possible to the font `like` (if any)
     /// - is as close as possible to the given `variant`
-    /// - is suitable for shaping the given `text`
     pub fn select_fallback(
         &self,
         like: Option<&FontInfo>,
         variant: FontVariant,
         text: &str,
+        lang: Option<Lang>,
+        region: Option<Region>,
     ) -> Option<usize> {
         // Find the fonts that contain the text's first char ...
         let c = text.chars().next()?;
@@ -101,6 +139,14 @@ impl FontBook {
             .filter(|(_, info)| info.coverage.contains(c as u32))
             .map(|(index, _)| index);
 
+        // ... prefer those designed for the requested region ...
+        let wanted = lang.and_then(|lang| cjk_region_for(lang, region));
+        let matches = |id: &usize| cjk_region(&self.infos[*id].family) == wanted;
+        let mut ids: Vec<_> = ids.collect();
+        if wanted.is_some() && ids.iter().any(matches) {
+            ids.retain(matches);
+        }
+
         // ... and find the best variant among them.
         self.find_best_variant(like, variant, ids)
     }
@@ -163,6 +209,35 @@ impl FontBook {
     }
 }
 
+/// The CJK region a font is designed for, as indicated by the last word of
+/// its family name (e.g. `JP` in "Noto Serif CJK JP").
+fn cjk_region(family: &str) -> Option<&'static str> {
+    let mut words = family.split_whitespace();
+    if !words.clone().any(|word| word == "CJK" || word == "Han") {
+        return None;
+    }
+
+    let last = words.next_back()?;
+    ["JP", "KR", "SC", "TC", "HK"]
+        .into_iter()
+        .find(|tag| last.eq_ignore_ascii_case(tag))
+}
+
+/// The CJK region tag of the fonts that best fit the given language and
+/// region, if the language is written with Han characters.
+fn cjk_region_for(lang: Lang, region: Option<Region>) -> Option<&'static str> {
+    match lang.as_str() {
+        "ja" => Some("JP"),
+        "ko" => Some("KR"),
+        "zh" => Some(match region.as_ref().map(Region::as_str) {
+            Some("TW") => "TC",
+            Some("HK") => "HK",
+            _ => "SC",
+        }),
+        _ => None,
+    }
+}
+
 /// Properties of a single font.
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct FontInfo {
@@ -517,6 +592,46 @@ mod tests {
         assert_eq!(typographic_family("Font Ultra Bold"), "Font");
     }
 
+    #[test]
+    fn test_fallback_prefers_font_for_language() {
+        let jp = include_bytes!("../../../../assets/fonts/NotoSerifCJKjp-Regular.otf");
+        let sc = include_bytes!("../../../../assets/fonts/NotoSerifCJKsc-Regular.otf");
+        let book = FontBook::from_infos(
+            [sc.as_slice(), jp.as_slice()].into_iter().flat_map(FontInfo::iter),
+        );
+
+        let family = |lang: &str| {
+            let lang = lang.parse().ok();
+            let id = book
+                .select_fallback(None, FontVariant::default(), "骨", lang, None)
+                .unwrap();
+            book.info(id).unwrap().family.clone()
+        };
+
+        assert_eq!(family("ja"), "Noto Serif CJK JP");
+        assert_eq!(family("zh"), "Noto Serif CJK SC");
+    }
+
+    #[test]
+    fn test_family_chain_coverage() {
+        let latin = include_bytes!("../../../../assets/fonts/LinLibertine_R.ttf");
+        let cjk = include_bytes!("../../../../assets/fonts/NotoSerifCJKsc-Regular.otf");
+        let book = FontBook::from_infos(
+            [latin.as_slice(), cjk.as_slice()]
+                .into_iter()
+                .flat_map(FontInfo::iter),
+        );
+
+        let (covered, missing) = book.coverage(["linux libertine"], "Bone 骨");
+        assert_eq!(covered, ['B', 'o', 'n', 'e', ' ']);
+        assert_eq!(missing, ['骨']);
+
+        let chain = ["linux libertine", "noto serif cjk sc"];
+        let (covered, missing) = book.coverage(chain, "Bone 骨");
+        assert_eq!(covered, ['B', 'o', 'n', 'e', ' ', '骨']);
+        assert!(missing.is_empty());
+    }
+
     #[test]
     fn test_coverage() {
         #[track_caller]
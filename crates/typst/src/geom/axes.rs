@@ -292,6 +292,7 @@ impl BitAndAssign for Axes<bool> {
 cast! {
     Axes<Rel<Length>>,
     self => array![self.x, self.y].into_value(),
+    coord: Coord => coord.into(),
     array: Array => {
         let mut iter = array.into_iter();
         match (iter.next(), iter.next(), iter.next()) {
@@ -682,6 +682,58 @@ impl Color {
         }
     }
 
+    /// Makes a color more transparent by a given factor.
+    ///
+    /// A factor of `{50%}` halves the color's opacity, while `{100%}` makes it
+    /// fully transparent. Grayscale colors have no alpha channel and are
+    /// converted to RGB first.
+    ///
+    /// ```example
+    /// #set text(fill: black.transparentize(50%))
+    /// Half transparent
+    /// ```
+    #[func]
+    pub fn transparentize(
+        self,
+        /// The call span
+        span: Span,
+        /// The factor to make the color more transparent by.
+        factor: Ratio,
+    ) -> SourceResult<Color> {
+        let Some(alpha) = self.alpha() else {
+            return match self {
+                Self::Luma(_) => self.to_rgba().transparentize(span, factor),
+                _ => bail!(error!(span, "cannot transparentize CMYK color")
+                    .with_hint("try converting your color to RGB first")),
+            };
+        };
+        let factor = factor.get() as f32;
+        Ok(self.with_alpha((alpha * (1.0 - factor)).clamp(0.0, 1.0)))
+    }
+
+    /// Makes a color more opaque by a given factor.
+    ///
+    /// A factor of `{100%}` makes the color fully opaque. Grayscale colors
+    /// have no alpha channel and are converted to RGB first.
+    #[func]
+    pub fn opacify(
+        self,
+        /// The call span
+        span: Span,
+        /// The factor to make the color more opaque by.
+        factor: Ratio,
+    ) -> SourceResult<Color> {
+        let Some(alpha) = self.alpha() else {
+            return match self {
+                Self::Luma(_) => self.to_rgba().opacify(span, factor),
+                _ => bail!(error!(span, "cannot opacify CMYK color")
+                    .with_hint("try converting your color to RGB first")),
+            };
+        };
+        let factor = factor.get() as f32;
+        Ok(self.with_alpha((alpha + (1.0 - alpha) * factor).clamp(0.0, 1.0)))
+    }
+
     /// Increases the saturation of a color by a given factor.
     #[func]
     pub fn saturate(
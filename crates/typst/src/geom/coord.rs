@@ -0,0 +1,91 @@
+use super::*;
+
+/// A coordinate in two-dimensional space.
+///
+/// Coordinates are made up of two [relative lengths]($relative): A horizontal
+/// `x` and a vertical `y` component. They can be added to and subtracted from
+/// each other and are accepted wherever a drawing function expects a point,
+/// e.g. by [`line`]($line), [`polygon`]($polygon), and [`path`]($path). In
+/// those places, an array of two relative lengths works just as well. The
+/// [`place`]($place) function takes a coordinate as its `offset`.
+///
+/// # Example
+/// ```example
+/// #let origin = coord(10pt, 10pt)
+/// #let offset = coord(40pt, 20pt)
+/// #line(start: origin, end: origin + offset)
+///
+/// #(origin + offset).x \
+/// #(origin + offset).y
+/// ```
+///
+/// A coordinate has the following fields:
+/// - `x`: Its horizontal component.
+/// - `y`: Its vertical component.
+#[ty(scope)]
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Coord {
+    /// The horizontal component.
+    pub x: Rel<Length>,
+    /// The vertical component.
+    pub y: Rel<Length>,
+}
+
+impl Coord {
+    /// Create a new coordinate from its components.
+    pub const fn new(x: Rel<Length>, y: Rel<Length>) -> Self {
+        Self { x, y }
+    }
+}
+
+#[scope]
+impl Coord {
+    /// Creates a new coordinate.
+    ///
+    /// ```example
+    /// #coord(50%, 1cm)
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The horizontal component.
+        x: Rel<Length>,
+        /// The vertical component.
+        y: Rel<Length>,
+    ) -> Coord {
+        Self::new(x, y)
+    }
+}
+
+impl Debug for Coord {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "coord({:?}, {:?})", self.x, self.y)
+    }
+}
+
+impl Neg for Coord {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Add for Coord {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+sub_impl!(Coord - Coord -> Coord);
+
+impl From<Coord> for Axes<Rel<Length>> {
+    fn from(coord: Coord) -> Self {
+        Axes::new(coord.x, coord.y)
+    }
+}
+
+cast! {
+    type Coord,
+}
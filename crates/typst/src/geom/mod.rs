@@ -7,6 +7,7 @@ mod align;
 mod angle;
 mod axes;
 mod color;
+mod coord;
 mod corners;
 mod dir;
 mod ellipse;
@@ -32,6 +33,7 @@ pub use self::align::{Align, FixedAlign, HAlign, VAlign};
 pub use self::angle::{Angle, AngleUnit};
 pub use self::axes::{Axes, Axis};
 pub use self::color::{Color, ColorSpace, WeightedColor};
+pub use self::coord::Coord;
 pub use self::corners::{Corner, Corners};
 pub use self::dir::Dir;
 pub use self::ellipse::ellipse;
@@ -45,7 +47,7 @@ pub use self::ratio::Ratio;
 pub use self::rect::styled_rect;
 pub use self::rel::Rel;
 pub use self::scalar::Scalar;
-pub use self::shape::{Geometry, Shape};
+pub use self::shape::{FillRule, Geometry, Shape};
 pub use self::sides::{Side, Sides};
 pub use self::size::Size;
 pub use self::smart::Smart;
@@ -1,3 +1,5 @@
+use crate::eval::Cast;
+
 use super::*;
 
 /// A geometric shape with optional fill and stroke.
@@ -7,6 +9,8 @@ pub struct Shape {
     pub geometry: Geometry,
     /// The shape's background fill.
     pub fill: Option<Paint>,
+    /// The rule that determines which parts of the shape are filled.
+    pub fill_rule: FillRule,
     /// The shape's border stroke.
     pub stroke: Option<FixedStroke>,
 }
@@ -25,11 +29,34 @@ pub enum Geometry {
 impl Geometry {
     /// Fill the geometry without a stroke.
     pub fn filled(self, fill: Paint) -> Shape {
-        Shape { geometry: self, fill: Some(fill), stroke: None }
+        Shape {
+            geometry: self,
+            fill: Some(fill),
+            fill_rule: FillRule::default(),
+            stroke: None,
+        }
     }
 
     /// Stroke the geometry without a fill.
     pub fn stroked(self, stroke: FixedStroke) -> Shape {
-        Shape { geometry: self, fill: None, stroke: Some(stroke) }
+        Shape {
+            geometry: self,
+            fill: None,
+            fill_rule: FillRule::default(),
+            stroke: Some(stroke),
+        }
     }
 }
+
+/// A rule that determines which parts of a self-intersecting or nested shape
+/// count as its interior.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum FillRule {
+    /// A point is inside the shape if the path winds around it a non-zero
+    /// number of times, taking the direction of the path into account.
+    #[default]
+    NonZero,
+    /// A point is inside the shape if a ray from it crosses the path an odd
+    /// number of times, regardless of the path's direction.
+    EvenOdd,
+}
@@ -15,6 +15,7 @@ use typst_macros::{cast, Cast};
 
 use crate::diag::StrResult;
 use crate::eval::Bytes;
+use crate::geom::Smart;
 use crate::World;
 
 /// A raster or vector image.
@@ -30,6 +31,8 @@ struct Repr {
     kind: ImageKind,
     /// A text describing the image.
     alt: Option<EcoString>,
+    /// How the image should be scaled.
+    scaling: ImageScaling,
 }
 
 /// A kind of image.
@@ -43,43 +46,60 @@ pub enum ImageKind {
 
 impl Image {
     /// Create an image from a buffer and a format.
+    ///
+    /// If `exif_rotate` is true, raster images are rotated according to their
+    /// EXIF orientation. If a color space is given, raster images are
+    /// converted into it.
     #[comemo::memoize]
     pub fn new(
         data: Bytes,
         format: ImageFormat,
         alt: Option<EcoString>,
+        scaling: ImageScaling,
+        exif_rotate: bool,
+        color_space: Smart<ImageColorSpace>,
     ) -> StrResult<Self> {
         let kind = match format {
-            ImageFormat::Raster(format) => {
-                ImageKind::Raster(RasterImage::new(data, format)?)
-            }
+            ImageFormat::Raster(format) => ImageKind::Raster(RasterImage::new(
+                data,
+                format,
+                exif_rotate,
+                color_space,
+            )?),
             ImageFormat::Vector(VectorFormat::Svg) => {
                 ImageKind::Svg(SvgImage::new(data)?)
             }
         };
 
-        Ok(Self(Arc::new(Prehashed::new(Repr { kind, alt }))))
+        Ok(Self(Arc::new(Prehashed::new(Repr { kind, alt, scaling }))))
     }
 
     /// Create a possibly font-dependant image from a buffer and a format.
     #[comemo::memoize]
+    #[allow(clippy::too_many_arguments)]
     pub fn with_fonts(
         data: Bytes,
         format: ImageFormat,
         alt: Option<EcoString>,
+        scaling: ImageScaling,
+        exif_rotate: bool,
+        color_space: Smart<ImageColorSpace>,
         world: Tracked<dyn World + '_>,
         families: &[String],
     ) -> StrResult<Self> {
         let kind = match format {
-            ImageFormat::Raster(format) => {
-                ImageKind::Raster(RasterImage::new(data, format)?)
-            }
+            ImageFormat::Raster(format) => ImageKind::Raster(RasterImage::new(
+                data,
+                format,
+                exif_rotate,
+                color_space,
+            )?),
             ImageFormat::Vector(VectorFormat::Svg) => {
                 ImageKind::Svg(SvgImage::with_fonts(data, world, families)?)
             }
         };
 
-        Ok(Self(Arc::new(Prehashed::new(Repr { kind, alt }))))
+        Ok(Self(Arc::new(Prehashed::new(Repr { kind, alt, scaling }))))
     }
 
     /// The raw image data.
@@ -119,6 +139,11 @@ impl Image {
         self.0.alt.as_deref()
     }
 
+    /// How the image should be scaled.
+    pub fn scaling(&self) -> ImageScaling {
+        self.0.scaling
+    }
+
     /// The decoded image.
     pub fn kind(&self) -> &ImageKind {
         &self.0.kind
@@ -132,6 +157,7 @@ impl Debug for Image {
             .field("width", &self.width())
             .field("height", &self.height())
             .field("alt", &self.alt())
+            .field("scaling", &self.scaling())
             .finish()
     }
 }
@@ -164,6 +190,29 @@ impl From<VectorFormat> for ImageFormat {
     }
 }
 
+/// How an image should be scaled when it is displayed at a different size
+/// than its natural one.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum ImageScaling {
+    /// Scale with a smoothing algorithm such as bilinear interpolation. This
+    /// is the default and suited for photos.
+    #[default]
+    Smooth,
+    /// Scale with nearest-neighbor sampling to keep hard pixel edges. This is
+    /// suited for pixel art.
+    Pixelated,
+}
+
+/// A color space raster images can be converted into when decoding.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum ImageColorSpace {
+    /// Shades of gray. This is useful for black-and-white print and makes
+    /// embedded images smaller.
+    Gray,
+    /// Red, green, and blue channels.
+    Rgb,
+}
+
 cast! {
     ImageFormat,
     self => match self {
@@ -7,13 +7,19 @@ use image::codecs::gif::GifDecoder;
 use image::codecs::jpeg::JpegDecoder;
 use image::codecs::png::PngDecoder;
 use image::io::Limits;
-use image::{guess_format, ImageDecoder, ImageResult};
+use image::{guess_format, ColorType, ImageDecoder, ImageResult};
+use once_cell::sync::OnceCell;
 use typst_macros::Cast;
 
+use super::ImageColorSpace;
 use crate::diag::{bail, StrResult};
 use crate::eval::Bytes;
+use crate::geom::Smart;
 
-/// A decoded raster image.
+/// A raster image.
+///
+/// Only the image's header is read upfront. The pixels are decoded on first
+/// access, which usually only happens during export.
 #[derive(Clone, Hash)]
 pub struct RasterImage(Arc<Repr>);
 
@@ -21,33 +27,77 @@ pub struct RasterImage(Arc<Repr>);
 struct Repr {
     data: Bytes,
     format: RasterFormat,
-    dynamic: image::DynamicImage,
+    width: u32,
+    height: u32,
     icc: Option<Vec<u8>>,
+    orientation: Option<u16>,
+    color_space: Option<ImageColorSpace>,
+    dynamic: OnceCell<image::DynamicImage>,
 }
 
 impl RasterImage {
-    /// Decode a raster image.
+    /// Read a raster image's header.
+    ///
+    /// This is memoized by the hash of the data, so loading the same bytes
+    /// again, even in a later compilation, reuses the image and its pixels if
+    /// they were already decoded.
+    ///
+    /// If `exif_rotate` is true, the pixels are rotated and flipped according
+    /// to the image's EXIF orientation tag, if it has one. If a color space is
+    /// given, the pixels are converted into it.
     #[comemo::memoize]
-    pub fn new(data: Bytes, format: RasterFormat) -> StrResult<Self> {
-        fn decode_with<'a, T: ImageDecoder<'a>>(
+    pub fn new(
+        data: Bytes,
+        format: RasterFormat,
+        exif_rotate: bool,
+        color_space: Smart<ImageColorSpace>,
+    ) -> StrResult<Self> {
+        fn header_with<'a, T: ImageDecoder<'a>>(
             decoder: ImageResult<T>,
-        ) -> ImageResult<(image::DynamicImage, Option<Vec<u8>>)> {
+        ) -> ImageResult<((u32, u32), ColorType, Option<Vec<u8>>)> {
             let mut decoder = decoder?;
             let icc = decoder.icc_profile().filter(|icc| !icc.is_empty());
             decoder.set_limits(Limits::default())?;
-            let dynamic = image::DynamicImage::from_decoder(decoder)?;
-            Ok((dynamic, icc))
+            Ok((decoder.dimensions(), decoder.color_type(), icc))
         }
 
         let cursor = io::Cursor::new(&data);
-        let (dynamic, icc) = match format {
-            RasterFormat::Jpg => decode_with(JpegDecoder::new(cursor)),
-            RasterFormat::Png => decode_with(PngDecoder::new(cursor)),
-            RasterFormat::Gif => decode_with(GifDecoder::new(cursor)),
+        let ((mut width, mut height), color, mut icc) = match format {
+            RasterFormat::Jpg => header_with(JpegDecoder::new(cursor)),
+            RasterFormat::Png => header_with(PngDecoder::new(cursor)),
+            RasterFormat::Gif => header_with(GifDecoder::new(cursor)),
         }
         .map_err(format_image_error)?;
 
-        Ok(Self(Arc::new(Repr { data, format, dynamic, icc })))
+        let orientation = exif_rotate
+            .then(|| exif_orientation(&data, format))
+            .flatten()
+            .filter(|orientation| (2..=8).contains(orientation));
+
+        // Orientations from five upwards turn the image by 90 degrees.
+        if orientation.map_or(false, |orientation| orientation >= 5) {
+            std::mem::swap(&mut width, &mut height);
+        }
+
+        let color_space = color_space
+            .as_custom()
+            .filter(|&space| needs_conversion(color, space));
+
+        // The embedded profile describes the original color space.
+        if color_space.is_some() {
+            icc = None;
+        }
+
+        Ok(Self(Arc::new(Repr {
+            data,
+            format,
+            width,
+            height,
+            icc,
+            orientation,
+            color_space,
+            dynamic: OnceCell::new(),
+        })))
     }
 
     /// The raw image data.
@@ -62,17 +112,17 @@ impl RasterImage {
 
     /// The image's pixel width.
     pub fn width(&self) -> u32 {
-        self.dynamic().width()
+        self.0.width
     }
 
     /// The image's pixel height.
     pub fn height(&self) -> u32 {
-        self.dynamic().height()
+        self.0.height
     }
 
-    /// Access the underlying dynamic image.
+    /// Access the underlying dynamic image, decoding it if necessary.
     pub fn dynamic(&self) -> &image::DynamicImage {
-        &self.0.dynamic
+        self.0.dynamic.get_or_init(|| self.0.decode())
     }
 
     /// Access the ICC profile, if any.
@@ -81,11 +131,56 @@ impl RasterImage {
     }
 }
 
+impl Repr {
+    /// Decode the pixels and apply the orientation and color space.
+    ///
+    /// The header was already validated, so this only fails for corrupt
+    /// pixel data. In that case, a warning is logged and the image is
+    /// transparent, as export has no way to report errors.
+    fn decode(&self) -> image::DynamicImage {
+        fn decode_with<'a, T: ImageDecoder<'a>>(
+            decoder: ImageResult<T>,
+        ) -> ImageResult<image::DynamicImage> {
+            let mut decoder = decoder?;
+            decoder.set_limits(Limits::default())?;
+            image::DynamicImage::from_decoder(decoder)
+        }
+
+        let cursor = io::Cursor::new(&self.data);
+        let decoded = match self.format {
+            RasterFormat::Jpg => decode_with(JpegDecoder::new(cursor)),
+            RasterFormat::Png => decode_with(PngDecoder::new(cursor)),
+            RasterFormat::Gif => decode_with(GifDecoder::new(cursor)),
+        };
+
+        let mut dynamic = match decoded {
+            Ok(dynamic) => dynamic,
+            Err(err) => {
+                tracing::warn!("{}", format_image_error(err));
+                return image::DynamicImage::new_rgba8(self.width, self.height);
+            }
+        };
+
+        if let Some(orientation) = self.orientation {
+            apply_orientation(&mut dynamic, orientation);
+        }
+
+        if let Some(space) = self.color_space {
+            convert_color_space(&mut dynamic, space);
+        }
+
+        dynamic
+    }
+}
+
 impl Hash for Repr {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // The image is fully defined by data and format.
+        // The image is fully defined by data, format, and the conversion
+        // applied when decoding.
         self.data.hash(state);
         self.format.hash(state);
+        self.orientation.hash(state);
+        self.color_space.hash(state);
     }
 }
 
@@ -137,3 +232,210 @@ fn format_image_error(error: image::ImageError) -> EcoString {
         err => eco_format!("failed to decode image ({err})"),
     }
 }
+
+/// Try to read the EXIF orientation tag of a JPEG or PNG image.
+fn exif_orientation(data: &[u8], format: RasterFormat) -> Option<u16> {
+    let tiff = match format {
+        RasterFormat::Jpg => jpeg_exif(data)?,
+        RasterFormat::Png => png_exif(data)?,
+        RasterFormat::Gif => return None,
+    };
+    tiff_orientation(tiff)
+}
+
+/// Find the TIFF-structured EXIF payload in a JPEG's APP1 segment.
+fn jpeg_exif(data: &[u8]) -> Option<&[u8]> {
+    let mut rest = data.strip_prefix(&[0xFF, 0xD8])?;
+    while let [0xFF, marker, hi, lo, ..] = *rest {
+        // Metadata segments come before the start of scan.
+        if marker == 0xDA {
+            break;
+        }
+        let len = u16::from_be_bytes([hi, lo]) as usize;
+        let segment = rest.get(4..2 + len)?;
+        if marker == 0xE1 {
+            if let Some(tiff) = segment.strip_prefix(b"Exif\0\0") {
+                return Some(tiff);
+            }
+        }
+        rest = rest.get(2 + len..)?;
+    }
+    None
+}
+
+/// Find the TIFF-structured EXIF payload in a PNG's `eXIf` chunk.
+fn png_exif(data: &[u8]) -> Option<&[u8]> {
+    let mut rest = data.get(8..)?;
+    while rest.len() >= 8 {
+        let len = u32::from_be_bytes(rest[..4].try_into().ok()?) as usize;
+        let kind = &rest[4..8];
+        let chunk = rest.get(8..8 + len)?;
+        match kind {
+            b"eXIf" => return Some(chunk),
+            // Metadata chunks come before the image data.
+            b"IDAT" | b"IEND" => break,
+            _ => {}
+        }
+        rest = rest.get(12 + len..)?;
+    }
+    None
+}
+
+/// Read the orientation tag from the first IFD of a TIFF structure.
+fn tiff_orientation(tiff: &[u8]) -> Option<u16> {
+    let big_endian = match tiff.get(..2)? {
+        b"MM" => true,
+        b"II" => false,
+        _ => return None,
+    };
+
+    let u16_at = |i: usize| {
+        let bytes = tiff.get(i..i + 2)?.try_into().ok()?;
+        Some(if big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    };
+
+    let u32_at = |i: usize| {
+        let bytes = tiff.get(i..i + 4)?.try_into().ok()?;
+        Some(if big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    };
+
+    if u16_at(2)? != 42 {
+        return None;
+    }
+
+    let ifd = u32_at(4)? as usize;
+    let count = u16_at(ifd)? as usize;
+    (0..count).find_map(|k| {
+        let entry = ifd + 2 + 12 * k;
+        // The orientation tag has type SHORT and is stored inline.
+        if u16_at(entry)? == 0x0112 && u16_at(entry + 2)? == 3 {
+            u16_at(entry + 8)
+        } else {
+            None
+        }
+    })
+}
+
+/// Rotate and flip the pixels according to an EXIF orientation value.
+fn apply_orientation(image: &mut image::DynamicImage, orientation: u16) {
+    *image = match orientation {
+        2 => image.fliph(),
+        3 => image.rotate180(),
+        4 => image.flipv(),
+        5 => image.fliph().rotate270(),
+        6 => image.rotate90(),
+        7 => image.fliph().rotate90(),
+        8 => image.rotate270(),
+        _ => return,
+    };
+}
+
+/// Whether pixels of the given color type need to be converted to end up in
+/// the given color space.
+fn needs_conversion(color: ColorType, space: ImageColorSpace) -> bool {
+    match space {
+        ImageColorSpace::Gray => color.has_color(),
+        ImageColorSpace::Rgb => !color.has_color(),
+    }
+}
+
+/// Convert the pixels into the given color space, keeping the alpha channel.
+fn convert_color_space(image: &mut image::DynamicImage, space: ImageColorSpace) {
+    let color = image.color();
+    if !needs_conversion(color, space) {
+        return;
+    }
+
+    let alpha = color.has_alpha();
+    *image = match space {
+        ImageColorSpace::Gray if alpha => image.to_luma_alpha8().into(),
+        ImageColorSpace::Gray => image.to_luma8().into(),
+        ImageColorSpace::Rgb if alpha => image.to_rgba8().into(),
+        ImageColorSpace::Rgb => image.to_rgb8().into(),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::hash128;
+
+    #[test]
+    fn test_pixels_are_decoded_lazily() {
+        let data = include_bytes!("../../../../assets/files/rhino.png");
+        let full =
+            RasterImage::new(data.to_vec().into(), RasterFormat::Png, true, Smart::Auto)
+                .unwrap();
+
+        // Cut the image data short. The header is still intact, so the image
+        // loads fine and knows its size without decoding any pixels.
+        let idat = data.windows(4).position(|w| w == b"IDAT").unwrap();
+        let truncated = data[..idat + 16].to_vec().into();
+        let image =
+            RasterImage::new(truncated, RasterFormat::Png, true, Smart::Auto).unwrap();
+        assert_eq!((image.width(), image.height()), (full.width(), full.height()));
+        assert!(image.0.dynamic.get().is_none());
+
+        // Accessing the pixels decodes them. As they are corrupt, a warning
+        // is logged and the image turns out transparent.
+        let dynamic = image.dynamic();
+        assert!(image.0.dynamic.get().is_some());
+        assert_eq!((dynamic.width(), dynamic.height()), (full.width(), full.height()));
+        assert!(dynamic.to_rgba8().pixels().all(|p| p[3] == 0));
+    }
+
+    /// Encode a 2x1 JPEG with an EXIF orientation tag.
+    fn jpeg_with_orientation(orientation: u8) -> Bytes {
+        let buf = image::RgbImage::from_raw(2, 1, vec![0, 0, 0, 255, 255, 255]).unwrap();
+        let mut data = io::Cursor::new(vec![]);
+        image::DynamicImage::ImageRgb8(buf)
+            .write_to(&mut data, image::ImageFormat::Jpeg)
+            .unwrap();
+
+        // A big-endian TIFF header followed by an IFD with a single entry.
+        let mut exif = b"Exif\0\0MM\0\x2A\0\0\0\x08\0\x01".to_vec();
+        exif.extend([0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, orientation, 0, 0]);
+        exif.extend([0, 0, 0, 0]);
+
+        let mut segment = vec![0xFF, 0xE1];
+        segment.extend((exif.len() as u16 + 2).to_be_bytes());
+        segment.extend(exif);
+
+        let jpeg = data.into_inner();
+        [&jpeg[..2], &segment, &jpeg[2..]].concat().into()
+    }
+
+    #[test]
+    fn test_exif_orientation_rotates_pixels() {
+        let data = jpeg_with_orientation(6);
+        let rotated =
+            RasterImage::new(data.clone(), RasterFormat::Jpg, true, Smart::Auto).unwrap();
+        assert_eq!((rotated.width(), rotated.height()), (1, 2));
+
+        let unrotated =
+            RasterImage::new(data, RasterFormat::Jpg, false, Smart::Auto).unwrap();
+        assert_eq!((unrotated.width(), unrotated.height()), (2, 1));
+
+        // Images are deduplicated by hash, so the two must not collide.
+        assert_ne!(hash128(&rotated), hash128(&unrotated));
+    }
+
+    #[test]
+    fn test_gray_conversion() {
+        let data = include_bytes!("../../../../assets/files/rhino.png");
+        let space = Smart::Custom(ImageColorSpace::Gray);
+        let image =
+            RasterImage::new(data.to_vec().into(), RasterFormat::Png, true, space)
+                .unwrap();
+        assert!(!image.dynamic().color().has_color());
+        assert!(image.icc().is_none());
+    }
+}
@@ -183,7 +183,7 @@ fn load_svg_fonts(
                 }
 
                 // If we didn't find a match, select a fallback font.
-                if let Some(id) = book.select_fallback(like, variant, text) {
+                if let Some(id) = book.select_fallback(like, variant, text, None, None) {
                     if let Some(usvg_family) = load_into_db(id) {
                         span.font.families = vec![usvg_family];
                     }
@@ -32,6 +32,9 @@ use crate::eval::Tracer;
 use crate::syntax::Span;
 use crate::World;
 
+/// How many times to attempt layout before giving up on its convergence.
+const MAX_ITERATIONS: usize = 5;
+
 /// Typeset content into a fully layouted document.
 #[comemo::memoize]
 #[tracing::instrument(skip(world, tracer, content))]
@@ -52,7 +55,7 @@ pub fn typeset(
     let mut introspector = Introspector::new(&[]);
 
     // Relayout until all introspections stabilize.
-    // If that doesn't happen within five attempts, we give up.
+    // If that doesn't happen within a few attempts, we give up.
     loop {
         tracing::info!("Layout iteration {iter}");
 
@@ -78,10 +81,13 @@ pub fn typeset(
             break;
         }
 
-        if iter >= 5 {
+        if iter >= MAX_ITERATIONS {
             tracer.warn(
-                warning!(Span::detached(), "layout did not converge within 5 attempts",)
-                    .with_hint("check if any states or queries are updating themselves"),
+                warning!(
+                    Span::detached(),
+                    "layout did not converge within {MAX_ITERATIONS} attempts",
+                )
+                .with_hint("check if any states or queries are updating themselves"),
             );
             break;
         }
@@ -4,18 +4,67 @@ use std::mem;
 use std::ptr;
 
 use comemo::Prehashed;
-use ecow::{eco_vec, EcoString, EcoVec};
+use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 
 use super::{Content, Element, NativeElement, Selector, Vt};
-use crate::diag::{SourceResult, Trace, Tracepoint};
-use crate::eval::{cast, ty, Args, FromValue, Func, IntoValue, Value, Vm};
+use crate::diag::{SourceResult, StrResult, Trace, Tracepoint};
+use crate::eval::{cast, func, scope, ty, Args, FromValue, Func, IntoValue, Value, Vm};
 use crate::syntax::Span;
 
 /// A list of style properties.
-#[ty]
+///
+/// Styles are provided by the [`style`]($style) function. Besides passing them
+/// to [`measure`]($measure), you can look up the active value of any settable
+/// property with the `get` method.
+///
+/// ```example
+/// #set heading(numbering: "1.a")
+/// #style(styles => {
+///   styles.get(heading, "numbering")
+/// })
+/// ```
+#[ty(scope)]
 #[derive(Default, PartialEq, Clone, Hash)]
 pub struct Styles(EcoVec<Prehashed<Style>>);
 
+#[scope]
+impl Styles {
+    /// Returns the value of an element's settable property as it was set.
+    ///
+    /// The value is the one from the innermost set rule of the property, or
+    /// the property's default value if it was never set. It is returned
+    /// exactly as it was written in that set rule: Relative values like `em`
+    /// lengths are not resolved and properties that combine across set rules,
+    /// like strokes, only return the innermost rule's part.
+    #[func]
+    pub fn get(
+        &self,
+        /// The element whose property to look up.
+        elem: Element,
+        /// The name of the property.
+        field: EcoString,
+    ) -> StrResult<Value> {
+        let property = self.0.iter().rev().find_map(|entry| match &**entry {
+            Style::Property(property) if property.is(elem, &field) => {
+                Some(property.value.clone())
+            }
+            _ => None,
+        });
+
+        if let Some(value) = property {
+            return Ok(value);
+        }
+
+        elem.params()
+            .iter()
+            .find(|param| param.settable && param.name == field)
+            .map(|param| param.default.map_or(Value::None, |default| default()))
+            .ok_or_else(|| {
+                eco_format!("{} does not have a settable field {:?}", elem.name(), field)
+            })
+    }
+}
+
 impl Styles {
     /// Create a new, empty style list.
     pub fn new() -> Self {
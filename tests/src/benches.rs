@@ -9,6 +9,7 @@ use typst::World;
 use unscanny::Scanner;
 
 const TEXT: &str = include_str!("../typ/compiler/bench.typ");
+const TABLE: &str = "#table(columns: (auto, auto, 1fr), ..range(1500).map(i => [#i]))";
 const FONT: &[u8] = include_bytes!("../../assets/fonts/LinLibertine_R.ttf");
 
 main!(
@@ -20,6 +21,7 @@ main!(
     bench_typeset,
     bench_compile,
     bench_render,
+    bench_table,
 );
 
 fn bench_decode(iai: &mut Iai) {
@@ -56,7 +58,7 @@ fn bench_edit(iai: &mut Iai) {
 }
 
 fn bench_eval(iai: &mut Iai) {
-    let world = BenchWorld::new();
+    let world = BenchWorld::new(TEXT);
     let route = typst::eval::Route::default();
     let mut tracer = typst::eval::Tracer::new();
     iai.run(|| {
@@ -66,7 +68,7 @@ fn bench_eval(iai: &mut Iai) {
 }
 
 fn bench_typeset(iai: &mut Iai) {
-    let world = BenchWorld::new();
+    let world = BenchWorld::new(TEXT);
     let route = typst::eval::Route::default();
     let mut tracer = typst::eval::Tracer::new();
     let module = typst::eval::eval(
@@ -81,18 +83,24 @@ fn bench_typeset(iai: &mut Iai) {
 }
 
 fn bench_compile(iai: &mut Iai) {
-    let world = BenchWorld::new();
+    let world = BenchWorld::new(TEXT);
     let mut tracer = Tracer::new();
     iai.run(|| typst::compile(&world, &mut tracer));
 }
 
 fn bench_render(iai: &mut Iai) {
-    let world = BenchWorld::new();
+    let world = BenchWorld::new(TEXT);
     let mut tracer = Tracer::new();
     let document = typst::compile(&world, &mut tracer).unwrap();
     iai.run(|| typst::export::render(&document.pages[0], 1.0, Color::WHITE))
 }
 
+fn bench_table(iai: &mut Iai) {
+    let world = BenchWorld::new(TABLE);
+    let mut tracer = Tracer::new();
+    iai.run(|| typst::compile(&world, &mut tracer));
+}
+
 struct BenchWorld {
     library: Prehashed<Library>,
     book: Prehashed<FontBook>,
@@ -101,7 +109,7 @@ struct BenchWorld {
 }
 
 impl BenchWorld {
-    fn new() -> Self {
+    fn new(text: &str) -> Self {
         let font = Font::new(FONT.into(), 0).unwrap();
         let book = FontBook::from_fonts([&font]);
 
@@ -109,7 +117,7 @@ impl BenchWorld {
             library: Prehashed::new(typst_library::build()),
             book: Prehashed::new(book),
             font,
-            source: Source::detached(TEXT),
+            source: Source::detached(text),
         }
     }
 
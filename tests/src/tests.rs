@@ -1,6 +1,6 @@
 #![allow(clippy::comparison_chain)]
 
-use std::cell::{RefCell, RefMut};
+use std::cell::{Cell, RefCell, RefMut};
 use std::collections::{HashMap, HashSet};
 use std::env;
 use std::ffi::OsStr;
@@ -25,6 +25,7 @@ use typst::doc::{Document, Frame, FrameItem, Meta};
 use typst::eval::{eco_format, func, Bytes, Datetime, Library, NoneValue, Tracer, Value};
 use typst::font::{Font, FontBook};
 use typst::geom::{Abs, Color, Smart};
+use typst::image::{Image, ImageKind};
 use typst::syntax::{FileId, PackageVersion, Source, SyntaxNode, VirtualPath};
 use typst::{World, WorldExt};
 use typst_library::layout::{Margin, PageElem};
@@ -88,7 +89,7 @@ fn main() {
     let world = TestWorld::new(args.print);
 
     println!("Running tests...");
-    let results = WalkDir::new("typ")
+    let mut results = WalkDir::new("typ")
         .into_iter()
         .par_bridge()
         .filter_map(|entry| {
@@ -112,7 +113,7 @@ fn main() {
                 None
             }
         })
-        .map_with(world, |world, src_path| {
+        .map_with(world.clone(), |world, src_path| {
             let path = src_path.strip_prefix(TYP_DIR).unwrap();
             let png_path = Path::new(PNG_DIR).join(path).with_extension("png");
             let ref_path = Path::new(REF_DIR).join(path).with_extension("png");
@@ -132,6 +133,10 @@ fn main() {
         })
         .collect::<Vec<_>>();
 
+    if args.filter.is_empty() {
+        results.push(test_image_reuse(&world) as usize);
+    }
+
     let len = results.len();
     let ok = results.iter().sum::<usize>();
     if len > 1 {
@@ -217,6 +222,8 @@ struct TestWorld {
     book: Prehashed<FontBook>,
     fonts: Vec<Font>,
     paths: RefCell<HashMap<PathBuf, PathSlot>>,
+    /// How often binary files were read from disk.
+    reads: Cell<usize>,
 }
 
 #[derive(Clone)]
@@ -247,6 +254,7 @@ impl TestWorld {
             book: Prehashed::new(FontBook::from_fonts(&fonts)),
             fonts,
             paths: RefCell::default(),
+            reads: Cell::new(0),
         }
     }
 }
@@ -278,7 +286,10 @@ impl World for TestWorld {
     fn file(&self, id: FileId) -> FileResult<Bytes> {
         let slot = self.slot(id)?;
         slot.buffer
-            .get_or_init(|| read(&slot.system_path).map(Bytes::from))
+            .get_or_init(|| {
+                self.reads.set(self.reads.get() + 1);
+                read(&slot.system_path).map(Bytes::from)
+            })
             .clone()
     }
 
@@ -300,6 +311,12 @@ impl TestWorld {
         source
     }
 
+    /// Forget all loaded files, as a watching compiler does between
+    /// compilations.
+    fn reset(&mut self) {
+        self.paths.get_mut().clear();
+    }
+
     fn slot(&self, id: FileId) -> FileResult<RefMut<PathSlot>> {
         let root: PathBuf = match id.package() {
             Some(spec) => format!("packages/{}-{}", spec.name, spec.version).into(),
@@ -484,6 +501,58 @@ fn test(
     ok
 }
 
+/// Compile a document twice, reading its image from disk afresh each time,
+/// and check that the second compilation reuses the decoded pixels.
+fn test_image_reuse(world: &TestWorld) -> bool {
+    fn find_image(frame: &Frame) -> Option<Image> {
+        frame.items().find_map(|(_, item)| match item {
+            FrameItem::Group(group) => find_image(&group.frame),
+            FrameItem::Image(image, _, _) => Some(image.clone()),
+            _ => None,
+        })
+    }
+
+    let mut world = world.clone();
+    world.reads.set(0);
+
+    let mut images = vec![];
+    for _ in 0..2 {
+        world.reset();
+        world.set(Path::new("image-reuse.typ"), "#image(\"/files/rhino.png\")".into());
+        let Ok(document) = typst::compile(&world, &mut Tracer::new()) else {
+            break;
+        };
+
+        // Rendering decodes the pixels if they aren't already.
+        render(&document.pages);
+        images.extend(document.pages.iter().find_map(find_image));
+    }
+
+    let reused = match images.as_slice() {
+        [first, second] => match (first.kind(), second.kind()) {
+            (ImageKind::Raster(first), ImageKind::Raster(second)) => {
+                std::ptr::eq(first.dynamic(), second.dynamic())
+            }
+            _ => false,
+        },
+        _ => false,
+    };
+
+    let ok = world.reads.get() == 2 && reused;
+    let mut stdout = io::stdout().lock();
+    if ok {
+        writeln!(stdout, "image reuse ✔").unwrap();
+    } else {
+        writeln!(stdout, "image reuse ❌").unwrap();
+        writeln!(stdout, "  Read the image {} time(s).", world.reads.get()).unwrap();
+        if !reused {
+            writeln!(stdout, "  Decoded the image more than once.").unwrap();
+        }
+    }
+
+    ok
+}
+
 fn get_metadata<'a>(line: &'a str, key: &str) -> Option<&'a str> {
     line.strip_prefix(eco_format!("// {key}: ").as_str())
 }
@@ -64,3 +64,19 @@ Hello *#x*
 ---
 // Error: 12-26 set is only allowed directly in code and content blocks
 #{ let x = set text(blue) }
+
+---
+// Test looking up the active value of a property.
+// Ref: false
+#style(styles => test(styles.get(heading, "numbering"), none))
+#[
+  #set heading(numbering: "1.1")
+  #set text(size: 1.5em)
+  #style(styles => test(styles.get(heading, "numbering"), "1.1"))
+  #style(styles => test(styles.get(text, "size"), 1.5em))
+]
+#style(styles => test(styles.get(text, "size"), 11pt))
+
+---
+// Error: 18-45 heading does not have a settable field "body"
+#style(styles => styles.get(heading, "body"))
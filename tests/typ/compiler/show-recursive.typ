@@ -49,3 +49,10 @@
   - Nested
   - List
 - Recursive!
+
+---
+// Test that a show rule producing its own target errors instead of crashing.
+// Error: 22-38 maximum show rule depth exceeded
+// Hint: 22-38 check whether a show rule produces its own target
+#show heading: it => heading(it.body)
+= Heading
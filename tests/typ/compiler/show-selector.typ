@@ -38,6 +38,27 @@ the ```rs &mut T``` reference.
 == Blue
 === Green
 
+---
+// Test that a later, more specific show rule takes precedence and that rules
+// returning their element chain.
+// Ref: false
+#show heading: it => [#metadata("general") <rule>]
+#show heading.where(level: 1): it => [#metadata("specific") <rule>]
+= One
+== Two
+
+#[
+  #show heading: it => [#metadata("general") <chain>#it.body]
+  #show heading.where(level: 1): it => [#metadata("specific") <chain>#it]
+  = Three
+]
+
+#locate(loc => {
+  let values(label) = query(label, loc).map(it => it.value)
+  test(values(<rule>), ("specific", "general"))
+  test(values(<chain>), ("specific", "general"))
+})
+
 ---
 // Error: 7-35 this selector cannot be used with show
 #show selector(heading).or(figure): none
@@ -14,6 +14,13 @@
 #test(rgb("#133337").negate(), rgb(236, 204, 200))
 #test(white.lighten(100%), white)
 
+// Test alpha modification methods.
+#test(rgb(255, 0, 0).transparentize(50%), rgb(255, 0, 0, 50%))
+#test(rgb(255, 0, 0, 50%).transparentize(100%), rgb(255, 0, 0, 0%))
+#test(rgb(255, 0, 0, 50%).opacify(100%), rgb(255, 0, 0))
+#test(black.transparentize(50%), rgb(0, 0, 0, 50%))
+#test(black.opacify(50%), rgb(0, 0, 0))
+
 // Color mixing, in Oklab space by default.
 #test(rgb(color.mix(rgb("#ff0000"), rgb("#00ff00"))), rgb("#d0a800"))
 #test(rgb(color.mix(rgb("#ff0000"), rgb("#00ff00"), space: oklab)), rgb("#d0a800"))
@@ -288,3 +295,8 @@
 ---
 // Error: 2-36 failed to format datetime (insufficient information)
 #datetime.today().display("[hour]")
+
+---
+// Error: 2-46 cannot transparentize CMYK color
+// Hint: 2-46 try converting your color to RGB first
+#cmyk(10%, 20%, 30%, 40%).transparentize(50%)
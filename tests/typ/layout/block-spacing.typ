@@ -7,3 +7,42 @@ Hello
 There
 
 #block(spacing: 20pt)[Further down]
+
+---
+// Test that `above` wins over a smaller `below` of the previous block and
+// over the generic `spacing`.
+// Ref: false
+#set block(spacing: 2pt)
+#let y(label, loc) = query(label, loc).first().location().position().y
+
+#block(height: 10pt, below: 5pt)[#metadata(none) <a>]
+#block(height: 10pt, above: 20pt)[#metadata(none) <b>]
+#block(height: 10pt)[#metadata(none) <c>]
+#block(height: 10pt, below: 20pt)[#metadata(none) <d>]
+#block(height: 10pt, above: 5pt)[#metadata(none) <e>]
+
+#locate(loc => {
+  test(y(<b>, loc) - y(<a>, loc), 30pt)
+  test(y(<c>, loc) - y(<b>, loc), 12pt)
+  test(y(<e>, loc) - y(<d>, loc), 30pt)
+})
+
+---
+// Test that block spacing is dropped at the start of a region, even if an
+// invisible counter update precedes the paragraph there.
+// Ref: false
+#set page(height: 100pt)
+#let pos(label, loc) = query(label, loc).first().location().position()
+
+#block(height: 75pt)
+#counter("x").update(1)
+
+A#metadata(none) <a>
+
+#pagebreak()
+A#metadata(none) <b>
+
+#locate(loc => {
+  test(pos(<a>, loc).page, 2)
+  test(pos(<a>, loc).y, pos(<b>, loc).y)
+})
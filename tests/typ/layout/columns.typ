@@ -103,3 +103,22 @@ This is a normal page. Very normal.
 // Test a page with zero columns.
 // Error: 49-50 number must be positive
 #set page(height: auto, width: 7.05cm, columns: 0)
+
+---
+// Test that balancing evens out the columns on the last page only.
+// Ref: false
+#set page(width: 100pt, height: 50pt, margin: 0pt, columns: 2)
+#set columns(balance: true)
+#set block(spacing: 0pt)
+#for i in range(14) {
+  block(width: 100%, height: 10pt)[#metadata(i) <item>]
+}
+
+#locate(loc => {
+  let columns(page) = query(<item>, loc)
+    .map(it => it.location().position())
+    .filter(pos => pos.page == page)
+    .map(pos => if pos.x < 50pt { "left" } else { "right" })
+  test(columns(1), ("left",) * 5 + ("right",) * 5)
+  test(columns(2), ("left", "left", "right", "right"))
+})
@@ -48,3 +48,33 @@ First!
   But, soft! what light through yonder window breaks? It is the east, and Juliet
   is the sun.
 ]
+
+---
+// Ref: false
+// Test that automatic inset scales with the text size.
+#style(styles => {
+  let pad(size) = {
+    let boxed = measure(text(size: size, box(inset: auto)[x]), styles)
+    let plain = measure(text(size: size)[x], styles)
+    (boxed.width - plain.width) / 2
+  }
+  let small = pad(10pt)
+  let large = pad(20pt)
+  test(small > 0pt, true)
+  test(large > small, true)
+  test(calc.round(large / small, digits: 3), 2.0)
+})
+
+---
+// Test that auto-width boxes shrink and full-width boxes fill the line.
+// Ref: false
+#box(width: auto)[Hi]#metadata(none) <auto>
+
+#box(width: 100%)[Hi]#metadata(none) <full>
+
+#locate(loc => style(styles => {
+  let x(label) = query(label, loc).first().location().position().x
+  let hi = measure([Hi], styles).width
+  test(calc.abs(x(<auto>) - 10pt - hi) < 0.01pt, true)
+  test(calc.abs(x(<full>) - 110pt) < 0.01pt, true)
+}))
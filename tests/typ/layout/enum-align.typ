@@ -42,3 +42,39 @@
 ---
 // Error: 25-28 expected `start`, `left`, `center`, `right`, or `end`, found top
 #set enum(number-align: top)
+
+---
+// Ref: false
+// Test that end-aligned numbers line up their periods once the numbers reach
+// two digits.
+#let dots = state("dots", ())
+#set enum(numbering: n => {
+  [#n]
+  locate(loc => dots.update(it => it + (loc.position().x,)))
+  [.]
+})
+
+#for i in range(11) [+ Item]
+
+#locate(loc => {
+  let xs = dots.final(loc)
+  test(xs.len(), 11)
+  test(xs.all(x => x == xs.first()), true)
+})
+
+---
+// Ref: false
+// Test that start-aligned numbers don't line up their periods.
+#let dots = state("dots", ())
+#set enum(number-align: start, numbering: n => {
+  [#n]
+  locate(loc => dots.update(it => it + (loc.position().x,)))
+  [.]
+})
+
+#for i in range(11) [+ Item]
+
+#locate(loc => {
+  let xs = dots.final(loc)
+  test(xs.at(9) > xs.at(8), true)
+})
@@ -53,3 +53,20 @@
 ---
 // Error: 22-28 invalid numbering pattern
 #set enum(numbering: "(())")
+
+---
+// Test that full numbering includes the numbers of all ancestors.
+// Ref: false
+#let seen = state("seen", ())
+#set enum(full: true, numbering: (..nums) => {
+  let number = numbering("1.", ..nums)
+  seen.update(it => it + (number,))
+  number
+})
+
++ First
+  + Nested
+  + Nested
++ Second
+
+#locate(loc => test(seen.final(loc), ("1.", "1.1.", "1.2.", "2.")))
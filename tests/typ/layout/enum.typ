@@ -46,3 +46,21 @@ a + 0.
    [Second],
    enum.item(5)[Fifth]
 )
+
+---
+// Ref: false
+// Test continuing the numbering of an interrupted enumeration.
+#let numbers = state("numbers", ())
+#set enum(numbering: n => {
+  numbers.update(it => it + (n,))
+  [#n.]
+})
+
++ First
++ Second
+
+An interruption.
+
+#enum(start: auto)[Third][Fourth]
+
+#locate(loc => test(numbers.final(loc), (1, 2, 3, 4)))
@@ -28,3 +28,25 @@ This is the start and it goes on.
 // All three lines go to the next page.
 #set text(olive)
 #lorem(10)
+
+---
+// Ref: false
+// Test that the widow setting decides whether a last line may stand alone.
+#set page(height: 60pt, margin: 0pt)
+#set text(top-edge: 10pt, bottom-edge: 0pt)
+#set par(leading: 10pt)
+
+#let page-of(label, loc) = query(label, loc).first().location().page()
+
+// With widow prevention, the last two lines move together.
+A \ B \ #box[]<prevented>C \ D
+#pagebreak()
+
+// Without it, only the last line moves.
+#set text(costs: (widow: false))
+A \ B \ #box[]<allowed>C \ D
+
+#locate(loc => {
+  test(page-of(<prevented>, loc), 2)
+  test(page-of(<allowed>, loc), 3)
+})
@@ -0,0 +1,32 @@
+// Test rows and columns with minimum and maximum sizes.
+
+---
+// A row with a minimum is at least that large, but grows with its content.
+// Ref: false
+#style(styles => {
+  let height(body) = measure(grid(rows: (min: 1cm), body), styles).height
+  test(height[Hi], 1cm)
+  test(height(block(height: 2cm)), 2cm)
+})
+
+---
+// A row or column with a maximum doesn't grow beyond it.
+// Ref: false
+#style(styles => {
+  let size(body) = measure(grid(
+    columns: (min: 5pt, max: 1cm),
+    rows: (max: 1cm),
+    body,
+  ), styles)
+  test(size[Hi].height < 1cm, true)
+  test(size(block(width: 3cm, height: 3cm)), (width: 1cm, height: 1cm))
+  test(size(box(width: 1pt)).width, 5pt)
+})
+
+---
+// Error: 13-23 unexpected key "mni", valid keys are "min" and "max"
+#grid(rows: (mni: 1cm))[]
+
+---
+// Error: 13-23 boxes cannot have a bounded width
+#box(width: (min: 1cm))[A]
@@ -55,3 +55,47 @@ Not in list
 #set align(horizon)
 
 - ABCDEF\ GHIJKL\ MNOPQR
+
+---
+// Test that the indent moves marker and body, while the body indent only
+// moves the body.
+// Ref: false
+#let approx(a, b) = test(calc.abs(a - b) < 0.01pt, true)
+#set list(marker: [#metadata(none) <marker>-])
+#list[#metadata(none) <body>A]
+#list(body-indent: 0.5em + 10pt)[#metadata(none) <body>A]
+#list(indent: 10pt)[#metadata(none) <body>A]
+
+#locate(loc => {
+  let xs(label) = query(label, loc).map(it => it.location().position().x)
+  let (m1, m2, m3) = xs(<marker>)
+  let (b1, b2, b3) = xs(<body>)
+  approx(m2, m1)
+  approx(b2, b1 + 10pt)
+  approx(m3, m1 + 10pt)
+  approx(b3, b1 + 10pt)
+})
+
+---
+// Test that the marker sits on the first line of a multi-paragraph item,
+// unless it is aligned differently.
+// Ref: false
+#let approx(a, b) = test(calc.abs(a - b) < 0.01pt, true)
+#set list(marker: [#metadata(none) <marker>-])
+- #metadata(none) <body>First paragraph.
+
+  Second paragraph.
+
+#list(marker-align: bottom)[
+  #metadata(none) <body>First paragraph.
+
+  Second paragraph.
+]
+
+#locate(loc => {
+  let ys(label) = query(label, loc).map(it => it.location().position().y)
+  let (m1, m2) = ys(<marker>)
+  let (b1, b2) = ys(<body>)
+  approx(m1, b1)
+  test(m2 - b2 > 10pt, true)
+})
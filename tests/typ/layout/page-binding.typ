@@ -44,3 +44,26 @@
 ---
 // Error: 20-23 must be `left` or `right`
 #set page(binding: top)
+
+---
+// Ref: false
+// Test that the binding decides which side is the inside.
+#let x-positions(loc) = query(<start>, loc).map(it => it.location().position().x)
+
+#set page(margin: (inside: 30pt, outside: 10pt))
+#box[]<start>Left-bound
+#pagebreak()
+#box[]<start>Left-bound
+
+#locate(loc => test(x-positions(loc), (30pt, 10pt)))
+
+---
+// Ref: false
+#let x-positions(loc) = query(<start>, loc).map(it => it.location().position().x)
+
+#set page(binding: right, margin: (inside: 30pt, outside: 10pt))
+#box[]<start>Right-bound
+#pagebreak()
+#box[]<start>Right-bound
+
+#locate(loc => test(x-positions(loc), (10pt, 30pt)))
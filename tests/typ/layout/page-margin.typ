@@ -18,3 +18,34 @@
 
 // Ensure that specific margins override general margins.
 #[#set page(margin: (rest: 0pt, left: 20pt)); Overridden]
+
+---
+// Ref: false
+// Test that automatic margins scale with the smaller page dimension.
+#let margins = state("margins", ())
+#let record = locate(loc => margins.update(it => it + (loc.position(),)))
+
+#set page(margin: auto)
+#page(paper: "a4", record)
+#page(paper: "a5", record)
+
+#locate(loc => {
+  let (a4, a5) = margins.final(loc)
+  test(a4.x, a4.y)
+  test(a5.x, a5.y)
+  test(calc.abs((a4.x - 2.5cm) / 1pt) < 1e-6, true)
+  test(calc.abs(a5.x / a4.x - 148 / 210) < 1e-6, true)
+})
+
+---
+// Ref: false
+// Test that fractional margins center the content.
+#set page(margin: (x: 1fr, y: 10pt))
+#metadata(none) <start>Centered#metadata(none) <end>
+
+#locate(loc => style(styles => {
+  let x(label) = query(label, loc).first().location().position().x
+  let width = measure([Centered], styles).width
+  test(calc.abs(x(<start>) - (120pt - width) / 2) < 0.01pt, true)
+  test(calc.abs(120pt - x(<end>) - x(<start>)) < 0.01pt, true)
+}))
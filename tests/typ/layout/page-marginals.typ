@@ -22,3 +22,22 @@ answer it.
 
 #set page(header: none, height: auto, margin: (top: 15pt, bottom: 25pt))
 The END.
+
+---
+// Test that headers can differ between odd and even pages, for example to sit
+// on the outer edge.
+// Ref: false
+#let sides = state("sides", ())
+#set page(height: 60pt, header: locate(loc => {
+  let side = if calc.odd(loc.page()) { right } else { left }
+  sides.update(v => v + (repr(side),))
+  align(side)[Outer]
+}))
+
+First
+#pagebreak()
+Second
+#pagebreak()
+Third
+
+#locate(loc => test(sides.final(loc), ("right", "left", "right")))
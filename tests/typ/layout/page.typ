@@ -14,6 +14,8 @@
 // Set width and height.
 // Should result in one high and one wide page.
 #set page(width: 80pt, height: 80pt)
+// Warning: 26-30 "High" is wider than the available space
+// Hint: 26-30 enabling hyphenation may help
 #[#set page(width: 40pt);High]
 #[#set page(height: 40pt);Wide]
 
@@ -40,3 +42,15 @@
   h(1em)
   place(left, rect(width: 80pt, stroke: blue))
 })
+
+---
+// Test that a page with automatic height grows to fit content that is taller
+// than any paper size instead of breaking.
+// Ref: false
+#set page(width: 20pt, height: auto, margin: 0pt)
+#block(width: 10pt, height: 5000pt)
+#locate(loc => {
+  test(counter(page).final(loc), (1,))
+  test(loc.position().page, 1)
+  test(loc.position().y >= 5000pt, true)
+})
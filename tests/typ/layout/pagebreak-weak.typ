@@ -28,3 +28,33 @@ First
 #metadata("Some")
 #pagebreak()
 Third
+
+---
+// Adjacent weak breaks collapse into a single one.
+// Ref: false
+First
+#pagebreak(weak: true)
+#pagebreak(weak: true)
+Second
+#locate(loc => {
+  test(loc.page(), 2)
+  test(counter(page).final(loc), (2,))
+})
+
+---
+// Adjacent weak column breaks collapse, too.
+// Ref: false
+#set page(width: 100pt, height: 100pt)
+#let xs = state("xs", ())
+#let mark = locate(loc => xs.update(v => v + (loc.position(),)))
+#columns(3)[
+  #mark A
+  #colbreak(weak: true)#colbreak(weak: true)
+  #mark B
+]
+#locate(loc => {
+  let (a, b) = xs.final(loc)
+  test(a.page, b.page)
+  test(b.x - a.x < 40pt, true)
+  test(b.x > a.x, true)
+})
@@ -53,3 +53,28 @@ Lריווח #h(1cm) R
 
 // Error: 16-19 text direction must be horizontal
 #set text(dir: ttb)
+
+---
+// Ref: false
+// Test that a detected direction follows the first strong character of each
+// paragraph.
+#set text(font: ("Linux Libertine", "Noto Sans Arabic"))
+#let xs = state("xs", ())
+#let mark = locate(loc => xs.update(v => v + (loc.position().x,)))
+#show regex("ell|رحب"): it => mark + it
+
+#[
+  #set text(dir: "detect")
+  مرحبا Hello
+
+  Hello مرحبا
+]
+
+مرحبا Hello
+
+#locate(loc => {
+  let (rtl-arabic, rtl-latin, ltr-latin, ltr-arabic, arabic, latin) = xs.final(loc)
+  test(rtl-arabic > rtl-latin, true)
+  test(ltr-latin < ltr-arabic, true)
+  test(arabic < latin, true)
+})
@@ -49,3 +49,41 @@ Welcome \ here. Does this work well?
 #set text(dir: rtl)
 لآن وقد أظلم الليل وبدأت النجوم
 تنضخ وجه الطبيعة التي أعْيَتْ من طول ما انبعثت في النهار
+
+---
+// Test that the second line is indented by exactly the hanging indent, also
+// with justification.
+// Ref: false
+#set par(hanging-indent: 2em, justify: true)
+#let x(label, loc) = query(label, loc).first().location().position().x
+
+#metadata(none) <first>First line \
+#metadata(none) <second>second line.
+
+#locate(loc => test(x(<second>, loc) - x(<first>, loc), 20pt))
+
+---
+// Ref: false
+// Test that only the paragraph after a heading is not indented.
+#set par(first-line-indent: 1em, justify: true)
+#show heading: set text(10pt)
+
+= Heading
+#box[]<first>First paragraph.
+
+#box[]<second>Second paragraph.
+
+#set par(first-line-indent: (amount: 1em, all: true))
+= Heading
+#box[]<third>Third paragraph.
+
+#locate(loc => {
+  let x(label) = query(label, loc).first().location().position().x
+  test(x(<first>), 10pt)
+  test(x(<second>), 20pt)
+  test(x(<third>), 20pt)
+})
+
+---
+// Error: 29-53 unexpected key "size", valid keys are "amount" and "all"
+#set par(first-line-indent: (amount: 1em, size: 1em))
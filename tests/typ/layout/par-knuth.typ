@@ -28,3 +28,45 @@
   column([Simple with hyphens], "simple", true),
   column([Optimized with hyphens], "optimized", true),
 )
+
+---
+// Test that optimized line breaking with hyphenation spaces words more evenly
+// than simple line breaking with hyphenation.
+// Ref: false
+#set page(width: 152pt, height: auto)
+#set par(justify: true)
+#set text(font: "New Computer Modern", hyphenate: true)
+#show regex("\S+"): it => [#metadata(it.text) <word>] + it
+
+#let story = [
+  In olden times when wishing still helped one, there lived a king whose
+  daughters were all beautiful; and the youngest was so beautiful that the sun
+  itself, which has seen so much, was astonished whenever it shone in her face.
+]
+
+#[#set par(linebreaks: "simple"); #story]
+#pagebreak()
+#[#set par(linebreaks: "optimized"); #story]
+
+#locate(loc => style(styles => {
+  let space = measure([a b], styles).width - measure([ab], styles).width
+  let words = query(<word>, loc).map(it => (
+    word: it.value,
+    ..it.location().position(),
+  ))
+
+  // The squared deviation of each space from its natural width.
+  let unevenness(page) = {
+    let total = 0.0
+    let words = words.filter(it => it.page == page)
+    for (a, b) in words.zip(words.slice(1)) {
+      if a.y == b.y {
+        let delta = (b.x - a.x - measure(a.word, styles).width - space) / 1pt
+        total += delta * delta
+      }
+    }
+    total
+  }
+
+  test(unevenness(2) < unevenness(1), true)
+}))
@@ -34,3 +34,46 @@ fn main() {}
 - List
 
 Paragraph
+
+---
+// Ref: false
+// Test that font-relative leading grows below lines with larger text.
+#set text(10pt)
+#set par(leading: 1em)
+#box[]<a>A #text(20pt)[B] \
+#box[]<b>C \
+#box[]<c>D
+
+#locate(loc => {
+  let y(label) = query(label, loc).first().location().position().y
+  let first = y(<b>) - y(<a>)
+  let second = y(<c>) - y(<b>)
+  test(calc.abs((first - second - 10pt) / 1pt) < 0.01, true)
+})
+
+---
+// Ref: false
+// Test that zero leading places lines back-to-back.
+#set par(leading: 0pt)
+#let ys = state("ys", ())
+#show regex("[ABC]"): it => locate(loc => ys.update(v => v + (loc.position().y,))) + it
+A \ B \ C
+#style(styles => locate(loc => {
+  let (a, b, c) = ys.final(loc)
+  let height = measure([A], styles).height
+  test(b - a, height)
+  test(c - b, height)
+}))
+
+---
+// Ref: false
+// Test that negative leading never moves a line above the one before it.
+#set par(leading: -10pt)
+#let ys = state("ys", ())
+#show regex("[ABC]"): it => locate(loc => ys.update(v => v + (loc.position().y,))) + it
+A \ B \ C
+#locate(loc => {
+  let (a, b, c) = ys.final(loc)
+  test(b >= a, true)
+  test(c >= b, true)
+})
@@ -9,6 +9,30 @@
 #place(auto, float: true, rect[C])
 #place(auto, float: true, rect[D])
 
+---
+// Test that top floats stack with clearance and overflow to the next page.
+// Ref: false
+#set page(height: 200pt, margin: 10pt)
+#set place(clearance: 10pt)
+#let float(name) = place(top, float: true, block(height: 60pt, metadata(name)))
+
+#float("a")
+#float("b")
+#float("c")
+Body
+
+#locate(loc => {
+  let pos(name) = query(metadata.where(value: name), loc)
+    .first()
+    .location()
+    .position()
+  test(pos("a").page, 1)
+  test(pos("b").page, 1)
+  test(pos("c").page, 2)
+  test(pos("b").y - pos("a").y, 70pt)
+  test(pos("c").y, pos("a").y)
+})
+
 ---
 // Error: 2-20 automatic positioning is only available for floating placement
 // Hint: 2-20 you can enable floating placement with `place(float: true, ..)`
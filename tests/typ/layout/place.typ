@@ -33,3 +33,24 @@ First
 #place(bottom + right)[Placed]
 
 Second
+
+---
+// Test that relative offsets resolve against the region.
+// Ref: false
+#let pos(label, loc) = query(label, loc).first().location().position()
+
+#block(width: 100pt, height: 40pt)[
+  #place(top + left)[#metadata(none) <origin>]
+  #place(top + left, dx: 50%, dy: 25%)[#metadata(none) <placed>]
+]
+
+#block(width: 100pt)[
+  #move[#metadata(none) <unmoved>]
+  #move(dx: 50%)[#metadata(none) <moved>]
+]
+
+#locate(loc => {
+  test(pos(<placed>, loc).x - pos(<origin>, loc).x, 50pt)
+  test(pos(<placed>, loc).y - pos(<origin>, loc).y, 10pt)
+  test(pos(<moved>, loc).x - pos(<unmoved>, loc).x, 50pt)
+})
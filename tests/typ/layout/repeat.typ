@@ -42,3 +42,24 @@ A#box(width: 1fr, repeat(rect(width: 6em, height: 0.7em)))B
 // Error: 2:2-2:13 repeat with no size restrictions
 #set page(width: auto)
 #repeat(".")
+
+---
+// Ref: false
+// Test that a leader fills exactly the space between the title and the page
+// number.
+#let leader = box(width: 1fr, repeat(gap: 2pt)[.])
+#box[Intro]<title>#leader#box[15]<page>
+
+#locate(loc => {
+  let title = query(<title>, loc).first().location().position()
+  let page = query(<page>, loc).first().location().position()
+  test(title.x, 10pt)
+  style(styles => {
+    let end = page.x + measure([15], styles).width
+    test(calc.abs(end - 110pt) < 0.01pt, true)
+  })
+})
+
+---
+// Error: 19-24 expected length, found color
+#repeat(".", gap: black)
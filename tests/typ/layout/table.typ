@@ -36,3 +36,149 @@
 ---
 // Error: 14-19 expected color, none, array, or function, found string
 #table(fill: "hey")
+
+---
+// Test that cells beyond the columns wrap into new rows and that a shorter
+// fill array cycles through the columns.
+// Ref: false
+#style(styles => {
+  let cells = range(5).map(str)
+  let size = measure(table(columns: 2, rows: 10pt, inset: 0pt, ..cells), styles)
+  test(size.height, 30pt)
+
+  let tracks = measure(grid(columns: (10pt,) * 3, ..cells), styles)
+  test(tracks.width, 30pt)
+})
+
+#table(columns: 5, fill: (red, blue), ..range(5).map(str))
+
+---
+// Test inset as a function of the cell position and per side.
+// Ref: false
+#let approx(a, b) = test(calc.abs(a - b) < 0.01pt, true)
+#style(styles => {
+  let inset = (_, row) => if row == 0 { 10pt } else { 5pt }
+  let header = measure(table(inset: inset, [A]), styles).height
+  let both = measure(table(inset: inset, [A], [A]), styles).height
+  let body = both - header
+  test(header > body, true)
+  approx(header - body, 10pt)
+
+  let wide = measure(table(inset: (x: 10pt, y: 0pt), [A]), styles)
+  let tight = measure(table(inset: 0pt, [A]), styles)
+  approx(wide.width - tight.width, 20pt)
+  approx(wide.height, tight.height)
+})
+
+#table(columns: 2, inset: (_, row) => if row == 0 { 10pt } else { 5pt })[A][B][C][D]
+
+---
+// Ref: false
+// Test that grid lines don't occupy cells.
+#style(styles => {
+  let plain = table(columns: 2, [A], [B], [C], [D])
+  let lined = table(
+    columns: 2,
+    [A], [B],
+    grid.hline(y: 1, stroke: 2pt),
+    grid.vline(x: 1, start: 1),
+    [C], [D],
+  )
+  test(measure(lined, styles), measure(plain, styles))
+})
+
+---
+// Ref: false
+// Test that an array of alignments is applied per column in all rows.
+#let xs = state("xs", ())
+#let cell = box(width: 10pt, locate(loc => {
+  xs.update(it => it + (loc.position().x,))
+}))
+
+#for func in (grid, table) {
+  let extra = if func == table { (inset: 0pt, stroke: none) } else { (:) }
+  xs.update(())
+  func(
+    ..extra,
+    columns: (50pt, 50pt),
+    align: (left, right),
+    cell, cell,
+    cell, cell,
+  )
+  locate(loc => {
+    let xs = xs.at(loc)
+    test(xs.len(), 4)
+    test(xs.at(0), xs.at(2))
+    test(xs.at(1), xs.at(3))
+    test(xs.at(1) - xs.at(0), 90pt)
+  })
+}
+
+---
+// Ref: false
+// Test that paragraphs in a cell are spaced apart as usual and that the inset
+// surrounds them without any additional spacing.
+#let body = [
+  First paragraph.
+
+  Second paragraph.
+]
+
+#style(styles => {
+  let inner = measure(block(width: 120pt, body), styles)
+  let cell = measure(table(columns: 134pt, inset: 7pt, body), styles)
+  let single = measure(table(columns: 134pt, inset: 7pt)[First paragraph.], styles)
+  test(cell.width, inner.width + 14pt)
+  test(cell.height, inner.height + 14pt)
+  test(cell.height > 2 * single.height - 14pt, true)
+})
+
+---
+// Ref: false
+// Test that rounding the outer corners keeps the table's size.
+#style(styles => {
+  let cells = ([A], [B], [C], [D])
+  let square = measure(table(columns: 2, fill: red, ..cells), styles)
+  let rounded = measure(table(columns: 2, fill: red, radius: 5pt, ..cells), styles)
+  test(rounded, square)
+})
+
+---
+// Ref: false
+// Test that filling the gutter doesn't change the grid's size.
+#style(styles => {
+  let cells = ([A], [B], [C], [D])
+  for func in (grid, table) {
+    let plain = measure(func(columns: 2, gutter: 4pt, ..cells), styles)
+    let filled = measure(func(columns: 2, gutter: 4pt, gutter-fill: red, ..cells), styles)
+    test(filled, plain)
+  }
+})
+
+---
+// Test that cell content is aligned vertically within a row made tall by a
+// sibling cell.
+// Ref: false
+#set page(height: auto, margin: 0pt)
+#table(
+  columns: 2,
+  inset: 0pt,
+  stroke: none,
+  align: (x, y) => (left, horizon).at(x),
+  rect(height: 60pt),
+  [#metadata(none) <center>A],
+)
+#grid(
+  columns: 2,
+  rect(height: 60pt),
+  align(bottom)[#metadata(none) <bottom>A],
+  rect(height: 60pt),
+  [#metadata(none) <top>A],
+)
+
+#locate(loc => {
+  let y(label) = query(label, loc).first().location().position().y
+  test(y(<center>) > 20pt and y(<center>) < 40pt, true)
+  let gap = y(<top>) - y(<bottom>)
+  test(gap > 0pt and gap < 15pt, true)
+})
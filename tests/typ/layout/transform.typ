@@ -47,3 +47,43 @@ nor #xetex!
 #box(scale(r, x: 50%, y: 200%, origin: left + top))
 #box(scale(r, x: 50%, origin: center))
 #box(scale(r, x: 50%, y: 200%, origin: right + bottom))
+
+---
+// Test scaling content down to the available width.
+// Ref: false
+#let fit(body) = layout(size => style(styles => {
+  let width = measure(body, styles).width
+  if width <= size.width { return body }
+  let factor = size.width / width * 100%
+  scale(x: factor, y: factor, origin: top + left, body)
+}))
+
+#fit(box(width: 200pt)[#h(1fr)#metadata(none) <wide>])
+#fit(box(width: 50pt)[#h(1fr)#metadata(none) <narrow>])
+
+#locate(loc => {
+  let x(label) = query(label, loc).first().location().position().x
+  test(calc.abs(x(<wide>) - 110pt) < 0.01pt, true)
+  test(calc.abs(x(<narrow>) - 60pt) < 0.01pt, true)
+})
+
+---
+// Test that rotation with reflow sizes containers to the rotated content.
+// Ref: false
+#style(styles => {
+  let label = [Long label]
+  let plain = measure(label, styles)
+  let near(a, b) = calc.abs(a - b) < 0.01pt
+
+  let rotated = measure(rotate(90deg, reflow: true, label), styles)
+  test(near(rotated.width, plain.height), true)
+  test(near(rotated.height, plain.width), true)
+
+  let cell = measure(table(
+    inset: 0pt,
+    stroke: none,
+    rotate(-90deg, reflow: true, label),
+  ), styles)
+  test(near(cell.width, plain.height), true)
+  test(near(cell.height, plain.width), true)
+})
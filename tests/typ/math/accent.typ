@@ -27,3 +27,15 @@ $A^x != hat(A)^x != hat(hat(A))^x$
 ---
 // Test high base.
 $ tilde(integral), tilde(integral)_a^b, tilde(integral_a^b) $
+
+---
+// Test that accents sit above the base without widening it.
+// Ref: false
+#style(styles => {
+  let base = measure($x$, styles)
+  for accented in ($hat(x)$, $dot(x)$, $arrow(x)$) {
+    let size = measure(accented, styles)
+    test(size.width, base.width)
+    test(size.height > base.height, true)
+  }
+})
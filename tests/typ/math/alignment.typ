@@ -32,3 +32,14 @@ $
 a &=b & quad c&=d \
 e &=f & g&=h
 $
+
+---
+// Test that alignment points line up the relations of all lines. The
+// aligned block is exactly as wide as a single line made up of the widest
+// left side and the widest right side.
+// Ref: false
+#style(styles => {
+  let aligned = measure($ x &= y y y \ x x x &= y $, styles)
+  let single = measure($ x x x = y y y $, styles)
+  test(calc.abs(aligned.width - single.width) < 0.01pt, true)
+})
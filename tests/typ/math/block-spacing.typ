@@ -0,0 +1,49 @@
+// Test the spacing around block equations.
+
+---
+// Block equations take the spacing configured for them through a show-set
+// rule for blocks, which takes precedence over the paragraph spacing.
+// Ref: false
+#set block(spacing: 10pt)
+#show math.equation: set block(above: 4pt, below: 20pt)
+#let ys = state("ys", ())
+#show regex("lph|et"): it => {
+  locate(loc => ys.update(v => v + (loc.position().y,)))
+  it
+}
+
+#let eq = $ #box(width: 10pt, height: 20pt) $
+
+Alpha
+#eq
+Beta
+
+#style(styles => locate(loc => {
+  let (alpha, beta) = ys.final(loc)
+  let line = measure([Alpha], styles).height
+  let height = measure(eq, styles).height
+  test(calc.abs((beta - alpha - line - height - 24pt).pt()) < 0.01, true)
+}))
+
+---
+// Tall equations push the surrounding lines further apart than short ones,
+// because the spacing is measured from their actual height.
+// Ref: false
+#let ys = state("ys", ())
+#show regex("lph|et"): it => {
+  locate(loc => ys.update(v => v + (loc.position().y,)))
+  it
+}
+
+Alpha
+$ x $
+Beta
+
+Alpha
+$ (a + b) / (c + d / e) $
+Beta
+
+#locate(loc => {
+  let (a1, b1, a2, b2) = ys.final(loc)
+  test(b2 - a2 > b1 - a1 + 10pt, true)
+})
@@ -7,3 +7,12 @@ $ f(x, y) := cases(
   3 &"if" x in NN,
   4 &"else",
 ) $
+
+---
+// Test that cases with more branches grow the brace.
+// Ref: false
+#style(styles => {
+  let two = measure($cases(x &"if" a, y &"otherwise")$, styles)
+  let three = measure($cases(x &"if" a, y &"if" b, z &"otherwise")$, styles)
+  test(three.height > two.height, true)
+})
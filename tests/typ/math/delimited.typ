@@ -43,3 +43,16 @@ $ lr(
     text("(", fill: #green) a/b
     text(")", fill: #blue)
   ) $
+
+---
+// Test that matched parentheses grow with their content: The parentheses
+// around a fraction use a larger (and wider) glyph variant than the ones
+// around a single letter.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let small = width($ (a) $) - width($ a $)
+  let large = width($ (a/b) $) - width($ a/b $)
+  test(large > small, true)
+  test(measure($ (a/b) $, styles).height >= measure($ a/b $, styles).height, true)
+})
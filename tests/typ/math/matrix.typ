@@ -91,3 +91,15 @@ $ mat(1, 0, 0, 0; 0, 1, 0, 0; 0, 0, 1, 1) $
 ---
 // Error: 3-37 cannot draw a vertical line after column 3 of a matrix with 3 columns
 $ mat(1, 0, 0; 0, 1, 1; augment: #3) $,
+
+---
+// Test that the delimiters of a matrix scale with its content.
+// Ref: false
+#style(styles => {
+  let one = measure($mat(1, 2)$, styles)
+  let two = measure($mat(1, 2; 3, 4)$, styles)
+  let bare = measure($mat(delim: #none, 1, 2; 3, 4)$, styles)
+  test(two.height > one.height, true)
+  test(two.height >= bare.height, true)
+  test(two.width > bare.width, true)
+})
@@ -9,3 +9,14 @@ $ phi.alt := (1 + sqrt(5)) / 2 $ <ratio>
 
 With @ratio, we get
 $ F_n = round(1 / sqrt(5) phi.alt^n) $ <fib>
+
+---
+// Test that block equations are numbered with the pattern.
+// Ref: false
+#set math.equation(numbering: "(1)")
+$ a + b = c $ <first>
+#locate(loc => {
+  let eq = query(<first>, loc).first()
+  let nums = counter(math.equation).at(eq.location())
+  test(numbering(eq.numbering, ..nums), "(1)")
+})
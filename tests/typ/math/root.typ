@@ -43,3 +43,12 @@ $ root(2, x) quad
 $ √2^3 = sqrt(2^3) $
 $ √(x+y) quad ∛x quad ∜x $
 $ (√2+3) = (sqrt(2)+3) $
+
+---
+// Test that the radical sign grows with its radicand.
+// Ref: false
+#style(styles => {
+  let height(body) = measure(body, styles).height
+  test(height($ sqrt(a/b) $) > height($ a/b $), true)
+  test(height($ sqrt(a/b) $) > height($ sqrt(a) $), true)
+})
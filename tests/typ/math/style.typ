@@ -35,6 +35,18 @@ $text(#red, "time"^2) + sqrt("place")$
 #show math.equation: set text(font: "Fira Math")
 $ v := vec(1 + 2, 2 - 4, sqrt(3), arrow(x)) + 1 $
 
+---
+// Test that switching the math font changes glyph selection.
+// Ref: false
+#style(styles => {
+  let default = measure($x + y$, styles)
+  let fira = measure({
+    show math.equation: set text(font: "Fira Math")
+    $x + y$
+  }, styles)
+  test(default.width == fira.width, false)
+})
+
 ---
 // Test using rules for symbols
 #show sym.tack: it => $#h(1em) it #h(1em)$
@@ -19,3 +19,14 @@ $ x = overbracket(
 $ underbracket([1, 2/3], "relevant stuff")
           arrow.l.r.double.long
   overbracket([4/5,6], "irrelevant stuff") $
+
+---
+// Test that braces add a brace and an optional label below or above.
+// Ref: false
+#style(styles => {
+  let height(body) = measure(body, styles).height
+  let base = height($ x + y $)
+  test(height($ underbrace(x + y) $) > base, true)
+  test(height($ underbrace(x + y, "sum") $) > height($ underbrace(x + y) $), true)
+  test(height($ overbrace(x + y, "sum") $) > height($ overbrace(x + y) $), true)
+})
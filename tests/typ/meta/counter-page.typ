@@ -8,3 +8,22 @@
 #set page(numbering: "1 / 1")
 #counter(page).update(1)
 #lorem(20)
+
+---
+// Test that the final page count is available in the footer.
+// Ref: false
+#set page(
+  height: 50pt,
+  footer: locate(loc => {
+    let here = counter(page).at(loc).first()
+    let total = counter(page).final(loc).first()
+    let label = str(here) + " / " + str(total)
+    if here == 1 { test(label, "1 / 2") }
+    if here == 2 { test(label, "2 / 2") }
+    label
+  }),
+)
+
+First
+#pagebreak()
+Second
@@ -50,3 +50,25 @@ At Beta, it was #locate(loc => {
 #figure(caption: [Four 'C's], kind: image, supplement: "Figure")[_CCCC!_]
 #counter(figure.where(kind: image)).update(n => n + 3)
 #figure(caption: [Four 'D's], kind: image, supplement: "Figure")[_DDDD!_]
+
+---
+// Test that a counter which depends on its own final value produces a warning
+// instead of looping forever.
+// Ref: false
+// Warning: layout did not converge within 5 attempts
+// Hint: check if any states or queries are updating themselves
+#let c = counter("c")
+#locate(loc => c.update(c.final(loc).first() + 1))
+#c.display()
+
+---
+// Test that measuring content which steps a counter doesn't advance it.
+// Ref: false
+#let c = counter("measured")
+#c.step()
+#style(styles => {
+  let size = measure([#c.step() #c.step() Hi], styles)
+  test(size.width > 0pt, true)
+})
+#locate(loc => test(c.at(loc), (1,)))
+#locate(loc => test(c.final(loc), (1,)))
@@ -16,6 +16,15 @@ What's up?
 #set document(author: (123,))
 What's up?
 
+---
+// Ref: false
+#set document(date: none)
+#set document(date: datetime(year: 2023, month: 8, day: 15))
+
+---
+// Error: 21-33 expected datetime, none, or auto, found string
+#set document(date: "2023-08-15")
+
 ---
 Hello
 
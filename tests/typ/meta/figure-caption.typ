@@ -54,3 +54,33 @@
   caption: [Hi],
   supplement: [B],
 )
+
+---
+// Test caption position, gap, and a custom supplement.
+// Ref: false
+#let y(label, loc) = query(label, loc).first().location().position().y
+
+#figure(
+  [#metadata(none) <top-body>Body],
+  caption: figure.caption(position: top)[#metadata(none) <top-caption>Caption],
+  supplement: [Fig.],
+) <fig>
+
+#figure(
+  [#metadata(none) <gap-body>Body],
+  caption: figure.caption(position: top)[#metadata(none) <gap-caption>Caption],
+  gap: 20pt,
+)
+
+#locate(loc => {
+  let fig = query(<fig>, loc).first()
+  test(fig.supplement, [Fig.])
+  test(fig.caption.supplement, [Fig.])
+
+  let normal = y(<top-body>, loc) - y(<top-caption>, loc)
+  let wide = y(<gap-body>, loc) - y(<gap-caption>, loc)
+  test(normal > 0pt, true)
+
+  // The default gap is 0.65em, so 6.5pt.
+  test(calc.abs(wide - normal - 13.5pt) < 0.01pt, true)
+})
@@ -109,3 +109,50 @@ We can clearly see that @fig-cylinder and
   table(columns: 2)[a][b],
   caption: [The table with custom separator.],
 )
+
+---
+// Test numbering figures per chapter.
+// Ref: false
+#let found = state("found", ())
+#show regex("\d\.\d"): it => {
+  found.update(v => v + (it.text,))
+  it
+}
+
+#set heading(numbering: "1.")
+#show heading.where(level: 1): it => {
+  counter(figure.where(kind: image)).update(0)
+  it
+}
+#set figure(numbering: n => locate(loc => {
+  numbering("1.1", counter(heading).at(loc).first(), n)
+}))
+
+= One
+#figure(rect[A], caption: [First])
+#figure(rect[B], caption: [Second])
+
+= Two
+#figure(rect[C], caption: [Third])
+
+#locate(loc => test(found.final(loc), ("1.1", "1.2", "2.1")))
+
+---
+// Test that a sticky figure moves to the next page with the content after it,
+// while a regular one stays.
+// Ref: false
+#set page(height: 100pt, margin: 10pt)
+#block(height: 45pt)
+#figure(rect(height: 20pt), sticky: true) <sticky>
+#block(height: 30pt)
+
+#pagebreak()
+#block(height: 45pt)
+#figure(rect(height: 20pt)) <loose>
+#block(height: 30pt)
+
+#locate(loc => {
+  let page(label) = query(label, loc).first().location().page()
+  test(page(<sticky>), 2)
+  test(page(<loose>), 3)
+})
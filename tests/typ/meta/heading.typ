@@ -51,3 +51,89 @@ multiline.
 =
 Not in heading
 =Nope
+
+---
+// Ref: false
+// Test that prefixed headings put their supplement and number above the
+// title in the document, but before it in the outline.
+#let plain(it) = if it.has("text") {
+  it.text
+} else if it.has("children") {
+  it.children.map(plain).join()
+} else if it.func() == [ ].func() {
+  " "
+}
+
+#show outline.entry: it => {
+  test(plain(it.body), "Chapter\u{a0}1 Introduction")
+  it
+}
+
+#set heading(numbering: "1", supplement: [Chapter])
+#outline()
+
+#style(styles => {
+  let inline = measure(heading[Introduction], styles)
+  let prefixed = measure(heading(prefixed: true)[Introduction], styles)
+  test(prefixed.height > 1.5 * inline.height, true)
+})
+
+#heading(prefixed: true)[Introduction]
+
+---
+// Test that headings number with letters after resetting the counter for
+// appendices.
+// Ref: false
+#let nums = state("nums", ())
+#show heading: it => {
+  let n = numbering(it.numbering, ..counter(heading).at(it.location()))
+  nums.update(v => v + (n,))
+  it
+}
+
+#set heading(numbering: "1.1")
+= Introduction
+= Method
+
+#counter(heading).update(0)
+#set heading(numbering: "A.1")
+= Data
+== Raw
+= Code
+
+#locate(loc => test(nums.final(loc), ("1", "2", "A", "A.1", "B")))
+
+---
+// Test that a sticky heading moves to the next page with its body, unless
+// stickiness is disabled.
+// Ref: false
+#set page(height: 100pt, margin: 10pt)
+#block(height: 45pt)
+= Sticky <sticky>
+Body
+
+#pagebreak()
+#block(height: 45pt)
+#heading(sticky: false)[Loose] <loose>
+Body
+
+#locate(loc => {
+  let page(label) = query(label, loc).first().location().page()
+  test(page(<sticky>), 2)
+  test(page(<loose>), 3)
+})
+
+---
+// Test that wrapped lines of numbered headings align with the title.
+// Ref: false
+#set heading(numbering: "1")
+#heading(hanging-indent: true)[#metadata(none) <title>Long \ #metadata(none) <hung>title]
+
+= Long \ #metadata(none) <flush>title
+
+#locate(loc => {
+  let x(label) = query(label, loc).first().location().position().x
+  test(x(<title>) > 10pt, true)
+  test(calc.abs(x(<hung>) - x(<title>)) < 0.01pt, true)
+  test(x(<flush>), 10pt)
+})
@@ -75,3 +75,10 @@ Text <hey>
 Text <hey>
 // Error: 2-20 label occurs multiple times in the document
 #link(<hey>)[Nope.]
+
+---
+// Test link tooltips.
+// Ref: false
+#let it = link("https://typst.app", tooltip: "Typst's website")[Typst]
+#test(it.tooltip, "Typst's website")
+#it
@@ -0,0 +1,48 @@
+// Test the metadata element.
+
+---
+// Ref: false
+// Test that metadata is invisible but can be queried with its value intact.
+#let config = (title: "Report", draft: true, version: 3, tags: ("a", "b"))
+#metadata(config) <config>
+
+#style(styles => {
+  let size = measure(metadata(config), styles)
+  test(size.width, 0pt)
+  test(size.height, 0pt)
+})
+
+#locate(loc => {
+  let found = query(metadata, loc)
+  test(found.len(), 1)
+  test(found.first().value, config)
+  test(query(<config>, loc).first().value.tags, ("a", "b"))
+})
+
+---
+// Ref: false
+// Test building an index from metadata markers.
+#set page(height: 60pt)
+#let index(term) = [#metadata(term) <index>]
+#let entries(loc) = {
+  let pages = (:)
+  for marker in query(<index>, loc) {
+    let list = pages.at(marker.value, default: ())
+    let page = marker.location().page()
+    if page not in list {
+      list.push(page)
+    }
+    pages.insert(marker.value, list)
+  }
+  pages.keys().sorted().map(term => {
+    term + " ... " + pages.at(term).map(str).join(", ")
+  })
+}
+
+Foo #index("foo") and bar #index("bar") and foo #index("foo").
+#pagebreak()
+Nothing here.
+#pagebreak()
+Foo again #index("foo").
+
+#locate(loc => test(entries(loc), ("bar ... 1", "foo ... 1, 3")))
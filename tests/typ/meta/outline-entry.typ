@@ -58,3 +58,20 @@
 #outline(target: cite)
 #cite("arrgh", "distress",  supplement: [p. 22])
 #bibliography("/files/works.bib")
+
+---
+// Test that outline entries refer to the page of their heading, which their
+// link points to.
+// Ref: false
+#let pages = state("pages", ())
+#show outline.entry: it => {
+  pages.update(v => v + ((it.element.location().page(), it.page),))
+  it
+}
+
+#outline()
+= First
+#pagebreak()
+= Second
+
+#locate(loc => test(pages.final(loc), ((1, [1]), (2, [2]))))
@@ -58,3 +58,36 @@
 #outline(indent: n => (a: "dict"))
 
 = Heading
+
+---
+// Test that automatic indentation aligns the titles of entries whose numbers
+// have different lengths.
+// Ref: false
+#set heading(numbering: "1.")
+#let xs = state("xs", ())
+#show regex("ntry"): it => {
+  locate(loc => xs.update(v => v + (loc.position(),)))
+  it
+}
+
+#outline(indent: auto)
+#pagebreak()
+
+= Entry
+= Entry
+= Entry
+= Entry
+= Entry
+= Entry
+= Entry
+= Entry
+= Entry
+= Entry
+== Entry
+
+#locate(loc => {
+  let xs = xs.final(loc).filter(p => p.page == 1).map(p => p.x)
+  test(xs.len(), 11)
+  test(xs.slice(0, 10).all(x => calc.abs((x - xs.first()).pt()) < 0.01), true)
+  test(xs.last() > xs.first(), true)
+})
@@ -35,3 +35,48 @@ Ok ...
 
 = #text(blue)[Zusammen]fassung
 #lorem(10)
+
+---
+// Ref: false
+// Test that outlined and bookmarked are independent.
+#let entries = state("entries", ())
+#show outline.entry: it => {
+  entries.update(v => v + (it.element.body,))
+  it
+}
+
+#outline()
+
+= Outlined
+#heading(outlined: false, bookmarked: true)[Bookmarked]
+#heading(outlined: true, bookmarked: false)[Not bookmarked]
+
+#locate(loc => {
+  test(entries.final(loc), ([Outlined], [Not bookmarked]))
+  // An automatic bookmark follows the outlined property, like in the PDF
+  // export.
+  let is-bookmarked(it) = if it.bookmarked == auto { it.outlined } else { it.bookmarked }
+  let bookmarked = query(heading, loc).filter(is-bookmarked)
+  test(bookmarked.map(it => it.body), ([Outlined], [Bookmarked]))
+})
+
+---
+// Ref: false
+// Test that the outline only includes the configured range of levels.
+#let entries = state("entries", ())
+#show outline.entry: it => {
+  entries.update(v => v + ((it.element.body, it.level),))
+  it
+}
+
+#outline(depth: 2)
+#outline(min-depth: 2)
+
+= One
+== Two
+=== Three
+
+#locate(loc => test(entries.final(loc), (
+  ([One], 1), ([Two], 2),
+  ([Two], 2), ([Three], 3),
+)))
@@ -30,3 +30,34 @@
 
 = Approach
 #lorem(60)
+
+---
+// Test that the header shows the chapter of its page.
+// Ref: false
+#let shown = state("shown", ())
+#set page(
+  height: 80pt,
+  margin: 20pt,
+  header: locate(loc => {
+    let chapters = query(heading.where(level: 1), loc)
+      .filter(it => it.location().page() <= loc.page())
+    let title = if chapters.len() != 0 { chapters.last().body }
+    shown.update(v => v + (title,))
+    title
+  })
+)
+
+= Chapter 1
+#block(height: 30pt)
+#block(height: 30pt)
+
+#pagebreak()
+= Chapter 2
+#block(height: 30pt)
+
+#locate(loc => {
+  let titles = shown.final(loc)
+  test(titles.len() > 2, true)
+  test(titles.dedup(), ([Chapter 1], [Chapter 2]))
+  test(titles.last(), [Chapter 2])
+})
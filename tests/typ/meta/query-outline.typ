@@ -0,0 +1,26 @@
+// Test building a custom outline with the query function.
+
+---
+// Ref: false
+#let entries = state("entries", ())
+#let custom-outline = locate(loc => {
+  let items = query(heading, loc).map(it => {
+    let page = it.location().page()
+    entries.update(v => v + ((it.level, page, it.body),))
+    [#it.level: #it.body #h(1fr) #page]
+  })
+  items.join(linebreak())
+})
+
+#custom-outline
+
+= Introduction
+#pagebreak()
+== Details
+= Conclusion
+
+#locate(loc => test(entries.final(loc), (
+  (1, 1, [Introduction]),
+  (2, 2, [Details]),
+  (1, 2, [Conclusion]),
+)))
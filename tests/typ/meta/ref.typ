@@ -46,3 +46,48 @@ $ A = 1 $ <eq2>
 
 #set ref(supplement: none)
 @fig1, @fig2, @eq1, @eq2
+
+---
+// Ref: false
+// Test referencing the page of a figure.
+#let found = state("found", ())
+#[
+  #show regex("page|\d"): it => {
+    found.update(v => v + (it.text,))
+    it
+  }
+  See #ref(<fig>, form: "page").
+]
+
+#pagebreak()
+#figure([Content], caption: [A figure.]) <fig>
+
+#locate(loc => test(found.final(loc), ("page", "2")))
+
+---
+// Error: 17-23 expected "normal" or "page"
+#ref(<a>, form: "line")
+
+---
+// Ref: false
+// Test that references use the numbering of their target.
+#let found = state("found", ())
+#set heading(numbering: "1.1")
+= Introduction
+= Body
+== Detail <detail>
+
+#set heading(numbering: "A.1")
+#counter(heading).update(0)
+= Appendix
+== Extra <extra>
+
+#[
+  #show regex("[\dA-Z]\.\d"): it => {
+    found.update(v => v + (it.text,))
+    it
+  }
+  See @extra and @detail.
+]
+
+#locate(loc => test(found.final(loc), ("A.1", "2.1")))
@@ -6,3 +6,8 @@ The after fira 🏳️‍🌈!
 
 #set text(lang: "ar", font: "Noto Sans Arabic")
 مرحبًا
+
+---
+// Invisible text must still be selectable and searchable in the PDF.
+// Ref: false
+Visible #text(fill: none)[and invisible] text.
@@ -37,3 +37,24 @@
 ---
 // Error: 24-36 expected "baseline", "descender", "bounds", or length
 #set text(bottom-edge: "cap-height")
+
+---
+// Ref: false
+// Test that the cap height yields a tighter line box than the ascender.
+#let ascender = state("ascender")
+#let cap-height = state("cap-height")
+#style(styles => {
+  let measured(top) = measure(
+    text(font: "IBM Plex Mono", top-edge: top)[Hello],
+    styles,
+  ).height
+  ascender.update(measured("ascender"))
+  cap-height.update(measured("cap-height"))
+})
+
+#locate(loc => {
+  let ascender = ascender.final(loc)
+  let cap-height = cap-height.final(loc)
+  test(cap-height < ascender, true)
+  test(cap-height > 0pt, true)
+})
@@ -31,3 +31,22 @@ G // 5pt
 }
 
 #stack(dir: ltr, spacing: 1fr, square(size: size), square(size: 25pt))
+
+---
+// Test that nested em sizes compound.
+// Ref: false
+#let em-height(body) = style(styles => {
+  let size = measure(box(height: 1em), styles)
+  body(size.height)
+})
+
+#set text(size: 10pt)
+#[
+  #set text(size: 1.5em)
+  #[
+    #set text(size: 1.5em)
+    #em-height(h => test(h, 22.5pt))
+  ]
+  #em-height(h => test(h, 15pt))
+]
+#em-height(h => test(h, 10pt))
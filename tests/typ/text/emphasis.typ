@@ -26,6 +26,25 @@ Normal
 #set strong(delta: 150)
 *Medium* and *#[*Bold*]*
 
+---
+// Test that show rules can restyle emphasis and strong text.
+// Ref: false
+#let bodies = state("bodies", ())
+#show emph: it => {
+  bodies.update(v => v + (it.body,))
+  text(blue, it.body)
+}
+#show strong: it => text(red, it.body)
+
+_Blue_ and *red*, but #text(style: "italic")[this] is italic.
+
+#locate(loc => test(bodies.final(loc), ([Blue],)))
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width[_Hello_], width[Hello])
+  test(width[*Hello*], width[Hello])
+})
+
 ---
 // Error: 6-7 unclosed delimiter
 #box[_Scoped] to body.
@@ -42,6 +42,19 @@ fi vs. #text(ligatures: false)[No fi]
 #text(features: ("smcp",))[Smcp] \
 fi vs. #text(features: (liga: 0))[No fi]
 
+---
+// Test that nested feature settings merge.
+// Ref: false
+#style(styles => {
+  let size(body) = measure(body, styles)
+  let nested = text(features: (smcp: 1), text(features: (liga: false))[fi fi])
+  test(size(nested), size(text(features: (smcp: 1, liga: 0))[fi fi]))
+  test(size(nested) != size(text(features: (liga: 0))[fi fi]), true)
+
+  let overridden = text(features: (liga: 1), text(features: (liga: 0))[fi])
+  test(size(overridden), size(text(features: (liga: 0))[fi]))
+})
+
 ---
 // Error: 26-31 expected integer or none, found boolean
 #set text(stylistic-set: false)
@@ -59,5 +72,13 @@ fi vs. #text(features: (liga: 0))[No fi]
 #set text(features: false)
 
 ---
-// Error: 21-35 expected string, found boolean
-#set text(features: ("tag", false))
+// Error: 21-36 expected string, found boolean
+#set text(features: ("liga", false))
+
+---
+// Error: 21-38 feature tag must be four ASCII characters, found "toolong"
+#set text(features: ("toolong": true))
+
+---
+// Error: 21-29 feature tag must be four ASCII characters, found "ss1"
+#set text(features: ("ss1",))
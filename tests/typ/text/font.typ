@@ -43,6 +43,17 @@ Emoji: 🐪, 🌋, 🏞
 #text([Text], teal, font: "IBM Plex Serif") \
 #text(forest, font: "New Computer Modern", [Text]) \
 
+---
+// Test that invisible text is laid out like visible text.
+// Ref: false
+#style(styles => {
+  let visible = measure([Hello World], styles)
+  let invisible = measure(text(fill: none)[Hello World], styles)
+  test(invisible, visible)
+})
+
+#test(text(fill: none)[A].fill, none)
+
 ---
 // Error: 11-16 unexpected argument
 #set text(false)
@@ -37,6 +37,7 @@ It's a #emph[Tree]beard.
 #grid(
   columns: 2 * (20pt,),
   gutter: 20pt,
+  // Warning: 4-13 "Baran" is wider than the available space
   [Barankauf],
   [Bar-?ankauf],
 )
@@ -49,3 +50,37 @@ It's a #emph[Tree]beard.
 #set page(width: 60pt)
 #set text(hyphenate: true)
 #h(6pt) networks, the rest.
+
+---
+// Ref: false
+// Test that hyphenation exceptions take precedence over the patterns.
+#set text(hyphenate: true)
+#set text(hyphenation-exceptions: ("Database": "datab-ase"))
+#style(styles => block(width: measure([datab-], styles).width + 1pt)[
+  #box[]<start>database#box[]<end>
+])
+
+#locate(loc => style(styles => {
+  let x(label) = query(label, loc).first().location().position().x
+  let rest = measure([ase], styles).width
+  test(calc.abs((x(<end>) - x(<start>) - rest) / 1pt) < 0.01, true)
+}))
+
+---
+// Ref: false
+// Test that an exception without hyphens prevents hyphenation.
+#set text(hyphenate: true)
+#set text(hyphenation-exceptions: ("database": "database"))
+#style(styles => block(width: measure([datab-], styles).width + 1pt)[
+  #box[]<start>database#box[]<end>
+])
+
+#locate(loc => style(styles => {
+  let x(label) = query(label, loc).first().location().position().x
+  let word = measure([database], styles).width
+  test(calc.abs((x(<end>) - x(<start>) - word) / 1pt) < 0.01, true)
+}))
+
+---
+// Error: 35-60 hyphenated form "date-base" does not match the word "database"
+#set text(hyphenation-exceptions: ("database": "date-base"))
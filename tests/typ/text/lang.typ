@@ -6,7 +6,10 @@
 #grid(
   columns: 2 * (20pt,),
   gutter: 1fr,
+  // Warning: 20-21 "“Eingabeauf" is wider than the available space
+  // Warning: 21-40 "forderung”" is wider than the available space
   text(lang: "en")["Eingabeaufforderung"],
+  // Warning: 21-40 "rung“" is wider than the available space
   text(lang: "de")["Eingabeaufforderung"],
 )
 
@@ -42,6 +45,60 @@
 // Error: 19-23 expected three or four letter script code (ISO 15924 or 'math')
 #set text(script: "ab")
 
+---
+// Test French punctuation spacing.
+// Ref: false
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width(text(lang: "fr")[Bonjour!]) > width[Bonjour!], true)
+  test(width(text(lang: "fr")[Bonjour !]), width(text(lang: "fr")[Bonjour!]))
+  test(width(text(lang: "fr", spacing-before-punctuation: false)[Bonjour!]), width[Bonjour!])
+
+  // Colons in URLs and times are left alone.
+  test(width(text(lang: "fr")[https://typst.app]), width[https://typst.app])
+  test(width(text(lang: "fr")[12:30]), width[12:30])
+
+  // The space in front of the punctuation doesn't break.
+  let height(body) = measure(block(width: width[Bonjour] + 1pt, body), styles).height
+  test(height(text(lang: "fr")[Bonjour !]), height[Bonjour])
+  test(height(text(lang: "fr", spacing-before-punctuation: false)[Bonjour !]) > height[Bonjour], true)
+
+  // Guillemets are padded on their inner sides.
+  test(width(text(lang: "fr")[«Oui»]) > width[«Oui»], true)
+  test(width(text(lang: "fr")[« Oui »]), width(text(lang: "fr")[«Oui»]))
+  test(width(text(lang: "fr")[«Oui.»]), width(text(lang: "fr")[« Oui. »]))
+})
+
+French: #text(lang: "fr")[Bonjour ! Ça va? Oui; merci : très bien.]
+
+---
+// Test that the language is consulted for quotes, hyphenation, and
+// localized strings alike.
+// Ref: false
+#set text(lang: "de")
+#figure([], caption: [Ein Bild]) <bild>
+
+#locate(loc => test(query(<bild>, loc).first().supplement, [Abbildung]))
+#style(styles => {
+  let size(body) = measure(body, styles)
+  test(size["Hallo"], size[„Hallo“])
+
+  let word = [Donaudampfschifffahrt]
+  let height(hyphenate) = size(block(width: 3em, text(hyphenate: hyphenate, word))).height
+  test(height(true) > height(false), true)
+})
+
+---
+// Test language-aware casing.
+// Ref: false
+#set text(lang: "tr")
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width(upper[istanbul]), width[İSTANBUL])
+  test(width(lower[ISPARTA]), width[ısparta])
+  test(width(text(lang: "en", upper[istanbul])), width[ISTANBUL])
+})
+
 ---
 // Error: 17-21 expected string, found none
 #set text(lang: none)
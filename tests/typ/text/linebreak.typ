@@ -2,10 +2,16 @@
 
 ---
 // Test overlong word that is not directly after a hard break.
+// Warning: 1-33 "spaceexceedinglylongy." is wider than the available space
+// Hint: 1-33 enabling hyphenation may help
 This is a spaceexceedinglylongy.
 
 ---
 // Test two overlong words in a row.
+// Warning: 1-63 "Supercalifragilisticexpialidocious" is wider than the available space
+// Hint: 1-63 enabling hyphenation may help
+// Warning: 1-63 "Expialigoricmetrioxidation." is wider than the available space
+// Hint: 1-63 enabling hyphenation may help
 Supercalifragilisticexpialidocious Expialigoricmetrioxidation.
 
 ---
@@ -49,3 +55,56 @@ Second part
 ทีวีตรวจทานนอร์ทแฟรีเลคเชอร์โกลด์อัลบัมเชอร์รี่เย้วสโตร์กฤษณ์เคลมเยอบีร่าพ่อค้าบลูเบอร์รี่สหัสวรรษโฮปแคนูโยโย่จูนสตรอว์เบอร์รีซื่อบื้อเยนแบ็กโฮเป็นไงโดนัททอมสเตริโอแคนูวิทย์แดรี่โดนัทวิทย์แอปพริคอทเซอร์ไพรส์ไฮบริดกิฟท์อินเตอร์โซนเซอร์วิสเทียมทานโคโยตี้ม็อบเที่ยงคืนบุญคุณ
 
 
+
+---
+// Test that mixed CJK and Latin text may break between CJK characters and
+// between Latin words, but not within a Latin word.
+// Ref: false
+#set page(width: 80pt)
+#set text(hyphenate: false)
+#let cjk = state("cjk", ())
+#let latin = state("latin", ())
+#show regex("版"): it => {
+  locate(loc => cjk.update(v => v + (loc.position().y,)))
+  it
+}
+#show regex("yp|tin"): it => {
+  locate(loc => latin.update(v => v + (loc.position().y,)))
+  it
+}
+
+中文排版系统中文排版系统 typesetting 中文排版系统中文排版系统
+
+#locate(loc => {
+  let (start, end) = latin.final(loc)
+  test(start, end)
+  let cjk = cjk.final(loc)
+  test(cjk.len(), 4)
+  test(cjk.dedup().len() > 2, true)
+})
+
+---
+// Test that a word wider than the column is reported instead of silently
+// sticking out.
+// Ref: false
+#set page(width: 50pt)
+// Warning: 1-22 "Incomprehensibilities" is wider than the available space
+// Hint: 1-22 enabling hyphenation may help
+Incomprehensibilities
+
+---
+// Test that a word that can't be hyphenated is reported even though
+// hyphenation is enabled.
+// Ref: false
+#set page(width: 40pt)
+#set text(hyphenate: true)
+// Warning: 1-5 "High" is wider than the available space
+High
+
+---
+// Test that measuring doesn't warn about lines that don't fit.
+// Ref: false
+#style(styles => {
+  let size = measure(block(width: 1pt)[Incomprehensibilities], styles)
+  test(size.width > 1pt, true)
+})
@@ -21,3 +21,41 @@
 #set align(end)
 #set text(dir: rtl)
 :
+
+---
+// Test that a line-ending hyphen hangs into the margin.
+// Ref: false
+#set par(justify: true)
+aaa bbb#metadata(none) <on>-#linebreak(justify: true)
+ccc
+
+#text(overhang: false)[
+  aaa bbb#metadata(none) <off>-#linebreak(justify: true)
+  ccc
+]
+
+#locate(loc => style(styles => {
+  let x(label) = query(label, loc).first().location().position().x
+  let w = measure([-], styles).width
+  test(x(<on>) + w > 110pt, true)
+  test(x(<on>) > x(<off>), true)
+  test(calc.abs(x(<off>) + w - 110pt) < 0.01pt, true)
+}))
+
+---
+// Test that justified lines keep their optical edge flush with the margin.
+// Ref: false
+#set par(justify: true)
+aaa bbb#metadata(none) <period>.#linebreak(justify: true)
+aaa bbb#metadata(none) <hyphen>-#linebreak(justify: true)
+ccc
+
+#locate(loc => style(styles => {
+  let x(label) = query(label, loc).first().location().position().x
+  let period = measure([.], styles).width
+  let hyphen = measure([-], styles).width
+  test(x(<period>) + period > 110pt, true)
+  test(x(<hyphen>) + hyphen > 110pt, true)
+  test(calc.abs(x(<period>) + 0.2 * period - 110pt) < 0.01pt, true)
+  test(calc.abs(x(<hyphen>) + 0.45 * hyphen - 110pt) < 0.01pt, true)
+}))
@@ -0,0 +1,19 @@
+// Test highlighting of added and removed lines in diffs.
+
+---
+// Added lines are green, removed lines red and the rest is left alone.
+```diff
+@@ -1,2 +1,2 @@
+-let x = 1;
++let x = 2;
+ let y = x;
+```
+
+---
+// Headers are not highlighted.
+#set page(width: auto)
+```diff
++++ b/main.rs
+-removed
++added
+```
@@ -0,0 +1,13 @@
+// Test the background of inline raw text.
+
+---
+// The background hugs the glyphs and has rounded corners.
+#set raw(fill: luma(220), radius: 2pt)
+Call `main()` to start, then `exit()`.
+
+---
+// Lines with filled raw text keep their height.
+#set raw(fill: luma(220), radius: 2pt, inset: 3pt)
+#set par(leading: 2pt)
+A `fn` B \
+A B
@@ -0,0 +1,25 @@
+// Test show rules on the lines of raw blocks.
+
+---
+// Test that a `raw.line` show rule can highlight even lines.
+#let even = state("even", ())
+#show raw.line: it => {
+  if calc.even(it.number) {
+    even.update(v => v + ((it.number, it.count, it.text),))
+    box(fill: luma(230), it)
+  } else {
+    it
+  }
+}
+
+```typ
+= Title
+*Strong*
+_Emph_
+#lorem(2)
+```
+
+#locate(loc => test(even.final(loc), (
+  (2, 4, "*Strong*"),
+  (4, 4, "#lorem(2)"),
+)))
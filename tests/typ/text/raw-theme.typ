@@ -22,3 +22,16 @@
 #let hi = "Hello World"
 #show heading: emph
 ```
+
+---
+// Test that a theme's background and foreground are applied.
+// Ref: false
+#set page(width: 180pt)
+#set text(6pt)
+#set raw(theme: "/files/halcyon.tmTheme")
+#show raw: set block(inset: 4pt)
+
+```typ
+= Chapter 1
+#let hi = "Hello World"
+```
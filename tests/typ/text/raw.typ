@@ -73,7 +73,74 @@ The keyword ```rust let```.
 #show raw: set text(font: "Roboto")
 `Roboto`
 
+---
+// Ref: false
+// Test that wrapped lines are indented to the level of their line, after
+// the continuation marker.
+#set page(width: 100pt)
+#set raw(overflow: "wrap")
+
+#let positions = state("positions", ())
+#show regex("START|0123456789"): it => {
+  it.text.first()
+  locate(loc => positions.update(it => it + (loc.position(),)))
+  it.text.slice(1)
+}
+
+```
+fn main() {
+    START 0123456789
+}
+```
+
+#locate(loc => style(styles => {
+  let (start, wrapped) = positions.final(loc)
+  test(wrapped.x - start.x, measure(`↪`, styles).width)
+  test(start.y < wrapped.y, true)
+}))
+
 ---
 // Unterminated.
 // Error: 1-2:1 unclosed raw text
 `endless
+
+---
+// Test that the background of inline raw text widens it, but keeps the
+// height of its line.
+// Ref: false
+#style(styles => {
+  let plain = measure(`code`, styles)
+  let filled = measure({
+    set raw(fill: luma(230), radius: 2pt, inset: 3pt)
+    `code`
+  }, styles)
+  test(filled.width, plain.width + 6pt)
+  test(filled.height, plain.height)
+})
+
+---
+// Test that diff blocks highlight added and removed lines.
+// Ref: false
+```diff
++++ b/main.rs
+@@ -1,3 +1,3 @@
+ fn main() {
+-    println!("Hello");
++    println!("Hello World!");
+ }
+```
+
+---
+// Test that raw text disables ligatures unless they are enabled explicitly.
+// None of the test fonts has code ligatures like `->`, so we check with the
+// standard "fi" ligature instead.
+// Ref: false
+#show raw: set text(font: "Linux Libertine")
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  let separate = width(text(font: "Linux Libertine", size: 0.8em, ligatures: false)[fi])
+  let merged = width(text(font: "Linux Libertine", size: 0.8em)[fi])
+  test(separate != merged, true)
+  test(width(`fi`), separate)
+  test(width({ show raw: set text(ligatures: true); `fi` }), merged)
+})
@@ -16,6 +16,20 @@
 #set smartquote(quotes: (single: "a\u{0301}a\u{0301}"))
 "Double and 'Single' Quotes"
 
+---
+// Test that quotes in raw text stay straight.
+// Ref: false
+#let smart = state("smart", 0)
+#let straight = state("straight", 0)
+#show smartquote: it => { smart.update(n => n + 1); it }
+#show regex("\""): it => { straight.update(n => n + 1); it }
+"Prose" and `code "x"`
+
+#locate(loc => {
+  test(smart.final(loc), 2)
+  test(straight.final(loc), 2)
+})
+
 ---
 // Error: 25-28 expected 2 characters, found 1 character
 #set smartquote(quotes: "'")
@@ -40,3 +40,27 @@ A#"\n" B
 ---
 // Test that trailing space does not force a line break.
 LLLLLLLLLLLLLLLLLL R _L_
+
+---
+// Ref: false
+// Test that lines never break at a non-breaking space.
+#style(styles => {
+  let line = measure(block(width: 1pt)[Fig.], styles).height
+  let spaced = measure(block(width: 1pt)[Fig. 1], styles).height
+  let tied = measure(block(width: 1pt)[Fig.~1], styles).height
+  test(spaced > line, true)
+  test(tied, line)
+})
+
+---
+// Ref: false
+// Test that a non-breaking space stretches under justification.
+#set par(justify: true)
+#let xs = state("xs", ())
+#show "b": it => locate(loc => xs.update(v => v + (loc.position().x,))) + it
+#block(width: 100pt)[a~b #linebreak(justify: true)]
+#block(width: 100pt)[a~b]
+#locate(loc => {
+  let (stretched, natural) = xs.final(loc)
+  test(stretched > natural, true)
+})
@@ -29,3 +29,25 @@ My text has spaces.
 // Test word spacing relative to the font's space width.
 #set text(spacing: 50% + 1pt)
 This is tight.
+
+---
+// Ref: false
+// Test that measuring tracked and emboldened text yields its rendered width.
+#let check(key, body) = {
+  let start = state(key + "-start")
+  let end = state(key + "-end")
+  locate(loc => start.update(loc.position().x))
+  body
+  locate(loc => end.update(loc.position().x))
+  style(styles => {
+    let width = measure(body, styles).width
+    locate(loc => {
+      let rendered = end.final(loc) - start.final(loc)
+      test(calc.abs((rendered - width) / 1pt) < 0.01, true)
+    })
+  })
+}
+
+#check("tracked", text(tracking: 3pt)[Tracked]) \
+#check("tight", text(tracking: -0.05em)[Tight]) \
+#check("heavy", strong(delta: 500)[Heavy])
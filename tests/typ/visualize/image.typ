@@ -65,6 +65,18 @@ A #box(image("/files/tiger.jpg", height: 1cm, width: 80%)) B
 // Test parsing from svg data
 #image.decode(`<svg xmlns="http://www.w3.org/2000/svg" height="140" width="500"><ellipse cx="200" cy="80" rx="100" ry="50" style="fill:yellow;stroke:purple;stroke-width:2" /></svg>`.text, format: "svg")
 
+---
+// Ref: false
+// Test that text in SVG data is set with the document's fonts, falling back
+// to the text font for unknown families.
+#set text(font: "Roboto")
+#image.decode(
+  "<svg xmlns='http://www.w3.org/2000/svg' width='120' height='30'>"
+  + "<text x='4' y='20' font-family='Linux Libertine'>Libertine</text>"
+  + "<text x='64' y='20' font-family='Nonexistent'>Roboto</text></svg>",
+  format: "svg",
+)
+
 ---
 // Error: 2-168 failed to parse SVG (missing root node)
 #image.decode(`<svg height="140" width="500"><ellipse cx="200" cy="80" rx="100" ry="50" style="fill:yellow;stroke:purple;stroke-width:2" /></svg>`.text, format: "svg")
@@ -80,3 +92,77 @@ A #box(image("/files/tiger.jpg", height: 1cm, width: 80%)) B
 ---
 // Error: 2-83 failed to decode image (Format error decoding Png: Invalid PNG signature.)
 #image.decode(read("/files/tiger.jpg", encoding: none), format: "png", width: 80%)
+
+---
+// Test image scaling.
+// Ref: false
+#image("/files/rhino.png", width: 20pt, scaling: "smooth")
+#image("/files/rhino.png", width: 20pt, scaling: "pixelated")
+#image.decode(read("/files/rhino.png", encoding: none), width: 60pt, scaling: "pixelated")
+
+---
+// Error: 37-45 expected "smooth" or "pixelated"
+#image("/files/rhino.png", scaling: "blurry")
+
+---
+// Ref: false
+// Test converting an image to grayscale.
+#image("/files/rhino.png", width: 20pt, color-space: "gray")
+#image.decode(read("/files/rhino.png", encoding: none), color-space: "rgb")
+
+---
+// Error: 41-47 expected "gray", "rgb", or auto
+#image("/files/rhino.png", color-space: "cmyk")
+
+---
+// Ref: false
+// Test that a single given dimension preserves the aspect ratio.
+#let svg = bytes(
+  "<svg xmlns='http://www.w3.org/2000/svg' width='20' height='10'>"
+  + "<rect width='20' height='10' fill='red'/></svg>"
+)
+
+#style(styles => {
+  let size = measure(image.decode(svg, format: "svg", width: 4cm), styles)
+  test(size.width, 4cm)
+  test(size.height, 2cm)
+
+  let size = measure(image.decode(svg, format: "svg", height: 1cm), styles)
+  test(size.width, 2cm)
+  test(size.height, 1cm)
+
+  let size = measure(image.decode(svg, format: "svg"), styles)
+  test(size.width, 20pt)
+  test(size.height, 10pt)
+})
+
+---
+// Ref: false
+// Test that a tiled image covers exactly its area.
+#style(styles => {
+  let tiled = image("/files/rhino.png", width: 45pt, height: 25pt, tile: (10pt, 10pt))
+  let size = measure(tiled, styles)
+  test(size.width, 45pt)
+  test(size.height, 25pt)
+})
+
+---
+// Error: 2-73 tile size must be positive
+#image("/files/rhino.png", width: 40pt, height: 20pt, tile: (0pt, 10pt))
+
+---
+// Error: 2-78 image has more than 10000 tiles
+// Hint: 2-78 try using a larger tile size
+#image("/files/rhino.png", width: 100pt, height: 100pt, tile: (0.1pt, 0.1pt))
+
+---
+// Error: 26-82 cannot tile an image across an infinite area
+#style(styles => measure(image("/files/rhino.png", width: 100%, tile: (5pt, 5pt)), styles))
+
+---
+// Test that a missing image with a fallback shows the fallback and warns.
+// Ref: false
+// Warning: 18-31 file not found (searched at typ/visualize/missing.png)
+// Hint: 18-31 the fallback is shown instead
+#let img = image("missing.png", fallback: rect(width: 12pt, height: 5pt))
+#style(styles => test(measure(img, styles), (width: 12pt, height: 5pt)))
@@ -0,0 +1,27 @@
+// Test marks at the ends of lines and paths.
+
+---
+// Arrowheads in all directions.
+#set page(width: 120pt)
+#line(length: 60pt, marks: "->")
+#v(4pt)
+#line(length: 60pt, marks: "<-", stroke: 2pt + blue)
+#v(4pt)
+#line(length: 60pt, marks: "<->", stroke: (paint: red, dash: "dashed"))
+#v(4pt)
+#line(end: (40pt, 20pt), marks: "->")
+
+---
+// Other kinds of marks and explicit sizes.
+#line(length: 60pt, marks: (start: "bar", end: "circle"))
+#v(4pt)
+#line(length: 60pt, marks: (start: (kind: "arrow", size: 4pt), end: (kind: "arrow", size: 10pt)))
+
+---
+// Marks on a path follow the tangents at its ends.
+#path(
+  marks: "<->",
+  (0pt, 20pt),
+  ((30pt, 0pt), (-10pt, 0pt)),
+  (60pt, 20pt),
+)
@@ -49,3 +49,47 @@
 ---
 // Error: 14-26 expected relative length, found angle
 #line(start: (3deg, 10pt), length: 5cm)
+
+---
+// Ref: false
+// Test coordinate values.
+#let a = coord(10pt, 20%)
+#let b = coord(5pt, 30pt)
+#test(a.x, 10pt)
+#test(a.y, 20%)
+#test(a + b, coord(15pt, 20% + 30pt))
+#test(a - b, coord(5pt, 20% - 30pt))
+#test(-b, coord(-5pt, -30pt))
+#test(type(a), coord)
+#test(repr(b), "coord(5pt, 30pt)")
+
+// Coordinates are accepted wherever an array of two lengths is.
+#test(line(start: a, end: a + b).start, (10pt, 20%))
+#test(line(end: b).end, (5pt, 30pt))
+#test(polygon(a, b).vertices, ((10pt, 20%), (5pt, 30pt)))
+#test(path(a, (b, a)).vertices, ((10pt, 20%), ((5pt, 30pt), (10pt, 20%))))
+
+// Placement takes a coordinate as its offset.
+#let placed = place(offset: a + b)[A]
+#test(placed.dx, 15pt)
+#test(placed.dy, 20% + 30pt)
+#test(place(offset: a, dx: 1pt)[A].dx, 1pt)
+
+---
+// Error: 3-31 cannot add coord and array
+#(coord(1pt, 2pt) + (3pt, 4pt))
+
+---
+// Ref: false
+// Test line marks.
+#test(line(marks: "->").marks, (start: none, end: "arrow"))
+#test(line(marks: "<->").marks, (start: "arrow", end: "arrow"))
+#test(line(marks: none).marks, (start: none, end: none))
+#test(
+  line(marks: (start: "circle", end: (kind: "bar", size: 4pt))).marks,
+  (start: "circle", end: (kind: "bar", size: 4pt)),
+)
+
+---
+// Error: 14-18 expected "-", "->", "<-", or "<->"
+#line(marks: "=>")
@@ -34,3 +34,7 @@
 ---
 // Error: 10-17 point array must contain exactly two entries
 #polygon((50pt,))
+
+---
+// Error: 21-30 expected "non-zero" or "even-odd"
+#polygon(fill-rule: "winding", (0pt, 0pt), (10pt, 0pt), (0pt, 10pt))
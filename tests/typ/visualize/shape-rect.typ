@@ -50,6 +50,21 @@
 // Error: 15-38 unexpected key "cake", valid keys are "top-left", "top-right", "bottom-right", "bottom-left", "left", "top", "right", "bottom", and "rest"
 #rect(radius: (left: 10pt, cake: 5pt))
 
+---
+// Test that a per-side stroke only strokes the given sides.
+// Ref: false
+#let r = rect(stroke: (left: 2pt + red, bottom: 1pt))
+#test(r.stroke.keys(), ("left", "bottom"))
+#test(r.stroke.left.thickness, 2pt)
+#test(r.stroke.left.paint, red)
+#test(r.stroke.bottom.thickness, 1pt)
+#test(r.stroke.bottom.paint, auto)
+#r
+
+#let b = box(stroke: (left: 2pt + red, bottom: 1pt))[Hi]
+#test(b.stroke.keys(), ("left", "bottom"))
+#b
+
 ---
 // Error: 15-21 expected length, color, dictionary, stroke, none, or auto, found array
 #rect(stroke: (1, 2))
@@ -0,0 +1,33 @@
+// Test that shapes, decorations, and list markers without a paint of their
+// own take on the text's fill.
+
+---
+#set text(fill: blue)
+#set page(width: 120pt)
+#stack(
+  dir: ltr,
+  spacing: 5pt,
+  rect(width: 20pt, height: 20pt),
+  line(start: (0pt, 10pt), end: (20pt, 10pt)),
+  polygon((0pt, 20pt), (10pt, 0pt), (20pt, 20pt)),
+  path((0pt, 0pt), (20pt, 20pt), closed: false),
+)
+
+---
+// A stroke with only a thickness keeps the text's fill as its paint.
+#set text(fill: red)
+#line(length: 60pt, stroke: 3pt)
+#rect(width: 60pt, height: 10pt, stroke: 2pt)
+
+---
+// Decorations follow the fill of their text.
+#underline(text(red)[underlined])
+#text(blue, overline[overlined])
+#text(green, strike[struck])
+
+---
+// List and enum markers follow the text's fill.
+#set text(fill: red)
+- Bullet
++ Number
+/ Term: Description
@@ -95,3 +95,43 @@
   ((0%, 50%), (4%, 4%)),
   ((50%, 0%), (4%, 4%)),
 )
+
+---
+// Ref: false
+// Test the stroke shorthand forms.
+#let stroke-of(value) = line(stroke: value).stroke
+
+// A length sets only the thickness.
+#let s = stroke-of(2pt)
+#test(s.thickness, 2pt)
+#test(s.paint, auto)
+
+// A color sets only the paint.
+#let s = stroke-of(red)
+#test(s.thickness, auto)
+#test(s.paint, red)
+
+// Adding a length and a color sets both, in either order.
+#test(stroke-of(2pt + red).thickness, 2pt)
+#test(stroke-of(2pt + red).paint, red)
+#test(stroke-of(red + 2pt), stroke-of(2pt + red))
+
+// A dictionary can set every property.
+#let s = stroke-of((
+  paint: blue,
+  thickness: 1pt,
+  cap: "round",
+  join: "bevel",
+  dash: "dashed",
+))
+#test(s.paint, blue)
+#test(s.thickness, 1pt)
+#test(s.cap, "round")
+#test(s.join, "bevel")
+#test(s.dash, (array: (3pt, 3pt), phase: 0pt))
+
+// Unspecified properties are left unset.
+#let s = stroke-of((paint: blue))
+#test(s.thickness, auto)
+#test(s.cap, auto)
+#test(s.dash, auto)